@@ -31,7 +31,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     trade_logger.log_price_impact("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", 0.5);
     trade_logger.log_slippage("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", 0.2);
-    
+
+    // Print a one-shot submission/confirmation latency report from NozomiManager
+    let nozomi_manager = NozomiManager::new(config.clone()).await?;
+    info!("{}", nozomi_manager.confirmation_metrics().report());
+
     // Start monitoring
     let monitoring_handle = tokio::spawn(async move {
         if let Err(e) = monitoring.start().await {
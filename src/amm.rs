@@ -0,0 +1,65 @@
+/// Constant-product (`x*y=k`) swap math for the PumpSwap curve, used by
+/// [`crate::mev_detector::MEVDetector`] to turn tracked pool reserves into
+/// deterministic expected-profit numbers instead of guessing with RNG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstantProductPool {
+    pub reserve_sol: u128,
+    pub reserve_token: u128,
+    /// Swap fee in basis points (e.g. `30` = 0.30%).
+    pub fee_bps: u16,
+}
+
+impl ConstantProductPool {
+    pub fn new(reserve_sol: u128, reserve_token: u128, fee_bps: u16) -> Self {
+        Self { reserve_sol, reserve_token, fee_bps }
+    }
+
+    /// Output amount for swapping `amount_in` into the pool, in the
+    /// direction `sol_to_token` indicates (SOL -> token if true, token ->
+    /// SOL if false): `amount_out = reserve_out * amount_in_after_fee /
+    /// (reserve_in + amount_in_after_fee)`, where `amount_in_after_fee =
+    /// amount_in * (10_000 - fee_bps) / 10_000`.
+    pub fn swap_output(&self, amount_in: u128, sol_to_token: bool) -> u128 {
+        let (reserve_in, reserve_out) = if sol_to_token {
+            (self.reserve_sol, self.reserve_token)
+        } else {
+            (self.reserve_token, self.reserve_sol)
+        };
+
+        if reserve_in == 0 || reserve_out == 0 || amount_in == 0 {
+            return 0;
+        }
+
+        let amount_in_after_fee = amount_in * (10_000 - self.fee_bps as u128) / 10_000;
+        reserve_out * amount_in_after_fee / (reserve_in + amount_in_after_fee)
+    }
+
+    /// Reserves after executing a swap of `amount_in` in direction
+    /// `sol_to_token`, for chaining a front-run/back-run pair of swaps
+    /// through the same pool without mutating `self`.
+    pub fn apply_swap(&self, amount_in: u128, sol_to_token: bool) -> Self {
+        let amount_out = self.swap_output(amount_in, sol_to_token);
+
+        if sol_to_token {
+            Self {
+                reserve_sol: self.reserve_sol + amount_in,
+                reserve_token: self.reserve_token.saturating_sub(amount_out),
+                fee_bps: self.fee_bps,
+            }
+        } else {
+            Self {
+                reserve_token: self.reserve_token + amount_in,
+                reserve_sol: self.reserve_sol.saturating_sub(amount_out),
+                fee_bps: self.fee_bps,
+            }
+        }
+    }
+
+    /// Marginal spot price, in SOL per token, ignoring fees.
+    pub fn spot_price(&self) -> f64 {
+        if self.reserve_token == 0 {
+            return 0.0;
+        }
+        self.reserve_sol as f64 / self.reserve_token as f64
+    }
+}
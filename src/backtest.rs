@@ -0,0 +1,448 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{debug, info};
+
+use crate::config::Config;
+use crate::mev_detector::MEVDetector;
+use crate::money::LAMPORTS_PER_SOL;
+use crate::pool_model::PoolModel;
+use crate::pool_state_retriever::ScanningRetriever;
+use crate::proto::pumpswap::{PriceChangeType, PriceUpdate, TokenListing, TokenMetadata};
+use crate::utils::{calculate_price_impact, calculate_slippage, estimate_transaction_fee};
+
+/// Rough size of a single-hop PumpSwap buy/sell instruction once wrapped in
+/// a transaction, used as `estimate_transaction_fee`'s `transaction_size`
+/// input here since no real transaction is built during a replay.
+const BACKTEST_TX_SIZE_BYTES: usize = 250;
+
+/// A row of a recorded listing/price-update stream to replay, in the
+/// on-disk shape a `--backtest` file is loaded from. This exists rather
+/// than deserializing straight into `TokenListing`/`PriceUpdate` because
+/// those are generated from `proto/pumpswap.proto` without a `serde`
+/// derive; `to_token_listing`/`to_price_update` below fill in the rest of
+/// each message the same way `MEVOpportunity::from_proto` adapts a wire
+/// type into the domain one.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BacktestRecord {
+    pub event: BacktestEventKind,
+    pub timestamp: u64,
+    pub token_address: String,
+    #[serde(default)]
+    pub token_symbol: String,
+    #[serde(default)]
+    pub pool_address: String,
+    #[serde(default)]
+    pub initial_liquidity: u64,
+    /// Pool reserves at this event, used to price the entry fill.
+    /// Defaults to `initial_liquidity` on both sides (a 1:1 pool) when a
+    /// recording doesn't carry real reserves.
+    #[serde(default)]
+    pub reserve_in: u64,
+    #[serde(default)]
+    pub reserve_out: u64,
+    #[serde(default)]
+    pub price_usd: f64,
+    #[serde(default)]
+    pub price_sol: f64,
+    #[serde(default)]
+    pub liquidity_usd: f64,
+    #[serde(default)]
+    pub volume_1h: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BacktestEventKind {
+    Listing,
+    PriceUpdate,
+}
+
+/// A position opened by the replay loop, tracked in cost-basis/fraction
+/// terms rather than raw token units: the AMM's `reserve_out` side and a
+/// feed's `price_usd` aren't denominated the same way without per-token
+/// decimals the recording doesn't carry, so PnL is derived from the ratio
+/// between entry and current `price_usd` applied to the SOL actually spent.
+struct OpenPosition {
+    cost_lamports: u64,
+    remaining_fraction: f64,
+    entry_price_usd: f64,
+    peak_price_usd: f64,
+    ladder_progress: usize,
+    target_price: f64,
+    realized_pnl_lamports: f64,
+    opened_at: u64,
+}
+
+/// Aggregate result of replaying a recorded event stream through the
+/// detection/decision path in dry-run mode. `net_pnl_sol`, the win/loss
+/// split, the largest win/loss and the drawdown are net of the entry/exit
+/// fees `estimate_transaction_fee` attributed to each fill; `gross_pnl_sol`
+/// is before fees.
+#[derive(Debug, Clone, Default)]
+pub struct BacktestReport {
+    pub events_processed: usize,
+    pub mev_opportunities_detected: usize,
+    pub trades_opened: usize,
+    pub trades_skipped_on_slippage: usize,
+    pub trades_closed: usize,
+    pub wins: usize,
+    pub losses: usize,
+    pub gross_pnl_sol: f64,
+    pub total_fees_sol: f64,
+    pub net_pnl_sol: f64,
+    pub max_drawdown_sol: f64,
+    pub largest_win_sol: f64,
+    pub largest_loss_sol: f64,
+}
+
+impl BacktestReport {
+    pub fn hit_rate(&self) -> f64 {
+        if self.trades_closed == 0 {
+            return 0.0;
+        }
+        self.wins as f64 / self.trades_closed as f64 * 100.0
+    }
+}
+
+impl fmt::Display for BacktestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Backtest report")?;
+        writeln!(f, "  events processed:       {}", self.events_processed)?;
+        writeln!(f, "  MEV opportunities seen: {}", self.mev_opportunities_detected)?;
+        writeln!(f, "  trades opened:          {}", self.trades_opened)?;
+        writeln!(f, "  trades skipped (slip):  {}", self.trades_skipped_on_slippage)?;
+        writeln!(f, "  trades closed:          {}", self.trades_closed)?;
+        writeln!(f, "  hit rate:               {:.1}% ({}/{})", self.hit_rate(), self.wins, self.trades_closed)?;
+        writeln!(f, "  gross PnL:              {:.9} SOL", self.gross_pnl_sol)?;
+        writeln!(f, "  fees paid:              {:.9} SOL", self.total_fees_sol)?;
+        writeln!(f, "  net PnL:                {:.9} SOL", self.net_pnl_sol)?;
+        writeln!(f, "  max drawdown:           {:.9} SOL", self.max_drawdown_sol)?;
+        writeln!(f, "  largest win / loss:     {:.9} / {:.9} SOL", self.largest_win_sol, self.largest_loss_sol)?;
+        Ok(())
+    }
+}
+
+/// Replays `path` (a JSON array of [`BacktestRecord`]s, or a header'd CSV
+/// with the same columns) through `MEVDetector::analyze_opportunities` and
+/// a dry-run version of the sniper's snipe/exit decision path, producing no
+/// transactions and touching no network. Intended to let `--backtest` tune
+/// `min_liquidity`, `max_slippage`, the profit ladder and MEV strategy mix
+/// against recorded history before risking funds live.
+pub async fn run_backtest(path: &str, config: Arc<RwLock<Config>>) -> Result<BacktestReport> {
+    let mut records = load_records(path)?;
+    records.sort_by_key(|r| r.timestamp);
+    info!("Loaded {} backtest events from {}", records.len(), path);
+
+    let (
+        snipe_amount,
+        min_liquidity,
+        max_slippage,
+        max_gas_price,
+        target_tokens,
+        profit_ladder,
+        trailing_stop_percentage,
+        stop_loss_percentage,
+        position_timeout_secs,
+    ) = {
+        let guard = config.read().await;
+        (
+            guard.snipe_amount.0,
+            guard.min_liquidity.0,
+            guard.max_slippage,
+            guard.max_gas_price,
+            guard.target_tokens.clone(),
+            guard.profit_ladder.clone(),
+            guard.trailing_stop_percentage,
+            guard.stop_loss_percentage,
+            guard.position_timeout_secs,
+        )
+    };
+
+    let mut mev_detector = MEVDetector::new(config.clone());
+    let pool_model = PoolModel::ConstantProduct;
+    // A replay has no live account feed to resolve reserves/oracle prices
+    // from, so every call below hands `analyze_opportunities` an empty
+    // retriever — it falls back to the recorded listing/price-update
+    // snapshot, same as a live call site with nothing wired up yet.
+    let no_live_accounts = ScanningRetriever::new(Vec::new());
+
+    let mut positions: HashMap<String, OpenPosition> = HashMap::new();
+    let mut last_price: HashMap<String, f64> = HashMap::new();
+    let mut report = BacktestReport::default();
+    let mut equity_curve_sol = 0.0_f64;
+    let mut peak_equity_sol = 0.0_f64;
+
+    for record in &records {
+        report.events_processed += 1;
+
+        match record.event {
+            BacktestEventKind::Listing => {
+                let listing = to_token_listing(record);
+
+                let opportunities = mev_detector
+                    .analyze_opportunities(std::slice::from_ref(&listing), &[], &no_live_accounts)
+                    .await?;
+                report.mev_opportunities_detected += opportunities.len();
+
+                let meets_criteria = listing.initial_liquidity >= min_liquidity
+                    && (target_tokens.is_empty() || target_tokens.contains(&listing.token_address));
+
+                if !meets_criteria || positions.contains_key(&listing.token_address) {
+                    continue;
+                }
+
+                let reserve_in = if record.reserve_in > 0 { record.reserve_in } else { listing.initial_liquidity };
+                let reserve_out = if record.reserve_out > 0 { record.reserve_out } else { listing.initial_liquidity };
+
+                let predicted_output = pool_model.predicted_output(snipe_amount, (reserve_in, reserve_out));
+                if predicted_output == 0 {
+                    continue;
+                }
+
+                // `calculate_slippage` expects an impact-free reference
+                // output to diff the AMM's actual output against, the same
+                // way a live quote is compared against its pre-trade price.
+                let no_impact_output = ((snipe_amount as u128 * reserve_out as u128) / (reserve_in.max(1) as u128)) as u64;
+                let slippage = calculate_slippage(no_impact_output, predicted_output);
+                let price_impact = calculate_price_impact(&pool_model, snipe_amount, predicted_output, (reserve_in, reserve_out));
+
+                if slippage > max_slippage {
+                    debug!(
+                        "Skipping {}: {:.2}% slippage (impact {:.2}%) exceeds max_slippage of {:.2}%",
+                        listing.token_address, slippage, price_impact, max_slippage
+                    );
+                    report.trades_skipped_on_slippage += 1;
+                    continue;
+                }
+
+                // The entry fee buys no tokens, so it stays out of the cost
+                // basis and is booked straight against net PnL.
+                let entry_fee = estimate_transaction_fee(BACKTEST_TX_SIZE_BYTES, max_gas_price);
+                let cost_lamports = snipe_amount;
+                report.total_fees_sol += entry_fee as f64 / LAMPORTS_PER_SOL as f64;
+                report.net_pnl_sol -= entry_fee as f64 / LAMPORTS_PER_SOL as f64;
+
+                positions.insert(
+                    listing.token_address.clone(),
+                    OpenPosition {
+                        cost_lamports,
+                        remaining_fraction: 1.0,
+                        entry_price_usd: 0.0,
+                        peak_price_usd: 0.0,
+                        ladder_progress: 0,
+                        target_price: 0.0,
+                        realized_pnl_lamports: -(entry_fee as f64),
+                        opened_at: record.timestamp,
+                    },
+                );
+                report.trades_opened += 1;
+                debug!("Opened backtest position in {} ({:.2}% impact)", listing.token_address, price_impact);
+            }
+            BacktestEventKind::PriceUpdate => {
+                let previous = last_price.get(&record.token_address).copied();
+                let price_update = to_price_update(record, previous);
+                last_price.insert(record.token_address.clone(), record.price_usd);
+
+                let opportunities = mev_detector
+                    .analyze_opportunities(&[], std::slice::from_ref(&price_update), &no_live_accounts)
+                    .await?;
+                report.mev_opportunities_detected += opportunities.len();
+
+                let Some(position) = positions.get_mut(&record.token_address) else {
+                    continue;
+                };
+
+                // The first price seen after entry becomes cost basis,
+                // mirroring `rebalancer::evaluate_price_exit`.
+                if position.entry_price_usd == 0.0 {
+                    position.entry_price_usd = record.price_usd;
+                    position.peak_price_usd = record.price_usd;
+                    if let Some(rung) = profit_ladder.first() {
+                        position.target_price = position.entry_price_usd * (1.0 + rung.gain_percentage / 100.0);
+                    }
+                    continue;
+                }
+
+                position.peak_price_usd = position.peak_price_usd.max(record.price_usd);
+
+                // Mirrors `rebalancer::evaluate_timeout_exit`: a position
+                // held past `position_timeout_secs` is force-closed at the
+                // current price regardless of whether it's up or down.
+                if record.timestamp.saturating_sub(position.opened_at) >= position_timeout_secs && position.remaining_fraction > 0.0 {
+                    close_slice(position, position.remaining_fraction, record.price_usd, max_gas_price, &mut report);
+                }
+
+                while position.ladder_progress < profit_ladder.len()
+                    && position.remaining_fraction > 0.0
+                    && record.price_usd >= position.target_price
+                {
+                    let rung = profit_ladder[position.ladder_progress];
+                    let sell_fraction = rung.sell_fraction.min(position.remaining_fraction);
+                    position.ladder_progress += 1;
+                    if let Some(next_rung) = profit_ladder.get(position.ladder_progress) {
+                        position.target_price = position.entry_price_usd * (1.0 + next_rung.gain_percentage / 100.0);
+                    }
+                    close_slice(position, sell_fraction, record.price_usd, max_gas_price, &mut report);
+                }
+
+                let stop_loss_price = position.entry_price_usd * (1.0 - stop_loss_percentage / 100.0);
+                let trailing_stop_drawdown_pct = (position.peak_price_usd - record.price_usd) / position.peak_price_usd * 100.0;
+                let trailing_stop_hit = position.ladder_progress > 0 && trailing_stop_drawdown_pct >= trailing_stop_percentage;
+
+                if position.remaining_fraction > 0.0 && (record.price_usd <= stop_loss_price || trailing_stop_hit) {
+                    close_slice(position, position.remaining_fraction, record.price_usd, max_gas_price, &mut report);
+                }
+
+                if position.remaining_fraction <= 0.0 {
+                    finalize_trade(position, &mut report);
+                    positions.remove(&record.token_address);
+                }
+            }
+        }
+
+        equity_curve_sol = report.net_pnl_sol;
+        peak_equity_sol = peak_equity_sol.max(equity_curve_sol);
+        report.max_drawdown_sol = report.max_drawdown_sol.max(peak_equity_sol - equity_curve_sol);
+    }
+
+    // Mark-to-market whatever is still open at the last price seen for it,
+    // so a position that never hit a ladder rung or stop still counts
+    // toward the reported PnL instead of vanishing from the stats.
+    for (token_address, mut position) in positions.into_iter() {
+        let mark_price = last_price.get(&token_address).copied().unwrap_or(position.entry_price_usd);
+        if position.remaining_fraction > 0.0 {
+            close_slice(&mut position, position.remaining_fraction, mark_price, max_gas_price, &mut report);
+        }
+        finalize_trade(&mut position, &mut report);
+    }
+    peak_equity_sol = peak_equity_sol.max(report.net_pnl_sol);
+    report.max_drawdown_sol = report.max_drawdown_sol.max(peak_equity_sol - report.net_pnl_sol);
+
+    Ok(report)
+}
+
+/// Books realized PnL for selling `fraction` of a position's *initial* cost
+/// basis at `exit_price_usd`, charging one more `estimate_transaction_fee`
+/// against it the same way a live exit would pay its own submission fee.
+fn close_slice(position: &mut OpenPosition, fraction: f64, exit_price_usd: f64, max_gas_price: u64, report: &mut BacktestReport) {
+    if fraction <= 0.0 {
+        return;
+    }
+
+    let price_ratio = exit_price_usd / position.entry_price_usd;
+    let slice_cost_lamports = position.cost_lamports as f64 * fraction;
+    let exit_fee = estimate_transaction_fee(BACKTEST_TX_SIZE_BYTES, max_gas_price);
+
+    let gross_pnl_lamports = slice_cost_lamports * (price_ratio - 1.0);
+    let net_pnl_lamports = gross_pnl_lamports - exit_fee as f64;
+
+    position.realized_pnl_lamports += net_pnl_lamports;
+    position.remaining_fraction = (position.remaining_fraction - fraction).max(0.0);
+
+    report.total_fees_sol += exit_fee as f64 / LAMPORTS_PER_SOL as f64;
+    report.gross_pnl_sol += gross_pnl_lamports / LAMPORTS_PER_SOL as f64;
+    report.net_pnl_sol += net_pnl_lamports / LAMPORTS_PER_SOL as f64;
+}
+
+fn finalize_trade(position: &OpenPosition, report: &mut BacktestReport) {
+    report.trades_closed += 1;
+    let pnl_sol = position.realized_pnl_lamports / LAMPORTS_PER_SOL as f64;
+    if pnl_sol > 0.0 {
+        report.wins += 1;
+        report.largest_win_sol = report.largest_win_sol.max(pnl_sol);
+    } else {
+        report.losses += 1;
+        report.largest_loss_sol = report.largest_loss_sol.min(pnl_sol);
+    }
+}
+
+fn to_token_listing(record: &BacktestRecord) -> TokenListing {
+    TokenListing {
+        token_address: record.token_address.clone(),
+        token_symbol: record.token_symbol.clone(),
+        token_name: record.token_symbol.clone(),
+        timestamp: record.timestamp,
+        creator: String::new(),
+        initial_liquidity: record.initial_liquidity,
+        pool_address: record.pool_address.clone(),
+        metadata: TokenMetadata {
+            address: record.token_address.clone(),
+            symbol: record.token_symbol.clone(),
+            name: record.token_symbol.clone(),
+            decimals: 9,
+            logo_uri: String::new(),
+            description: String::new(),
+            website: String::new(),
+            twitter: String::new(),
+            telegram: String::new(),
+            verified: false,
+            market_cap: 0,
+            total_supply: 0,
+        },
+    }
+}
+
+fn to_price_update(record: &BacktestRecord, previous_price: Option<f64>) -> PriceUpdate {
+    let change_type = match previous_price {
+        Some(previous) if record.price_usd < previous => PriceChangeType::Decrease,
+        _ => PriceChangeType::Increase,
+    };
+
+    PriceUpdate {
+        token_address: record.token_address.clone(),
+        price_usd: record.price_usd,
+        price_sol: record.price_sol,
+        liquidity_usd: record.liquidity_usd,
+        volume_1h: record.volume_1h,
+        timestamp: record.timestamp,
+        change_type,
+    }
+}
+
+/// Loads a `--backtest` file, dispatching on its extension: `.csv` for a
+/// header'd comma-separated file with `BacktestRecord`'s fields as columns,
+/// anything else (typically `.json`) for a JSON array of the same.
+fn load_records(path: &str) -> Result<Vec<BacktestRecord>> {
+    let content = std::fs::read_to_string(path).with_context(|| format!("Failed to read backtest file {}", path))?;
+
+    if path.ends_with(".csv") {
+        parse_csv(&content).with_context(|| format!("Failed to parse backtest CSV {}", path))
+    } else {
+        serde_json::from_str(&content).with_context(|| format!("Failed to parse backtest JSON {}", path))
+    }
+}
+
+/// A minimal comma-separated reader for [`BacktestRecord`]'s own columns;
+/// it doesn't support quoting or embedded commas, which is fine for the
+/// plain numeric/address fields a recorded listing/price stream has.
+fn parse_csv(content: &str) -> Result<Vec<BacktestRecord>> {
+    let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+    let header = lines.next().ok_or_else(|| anyhow::anyhow!("CSV file has no header row"))?;
+    let columns: Vec<&str> = header.split(',').map(|c| c.trim()).collect();
+
+    let mut records = Vec::new();
+    for line in lines {
+        let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+        let mut row = serde_json::Map::new();
+        for (column, field) in columns.iter().zip(fields.iter()) {
+            // Whole numbers stay integers so they can fill `u64` columns
+            // such as `timestamp`; serde_json won't read a float into a u64.
+            let value = if let Ok(n) = field.parse::<u64>() {
+                serde_json::Value::from(n)
+            } else {
+                field
+                    .parse::<f64>()
+                    .map(|n| serde_json::Value::from(n))
+                    .unwrap_or_else(|_| serde_json::Value::String(field.to_string()))
+            };
+            row.insert(column.to_string(), value);
+        }
+        records.push(serde_json::from_value(serde_json::Value::Object(row))?);
+    }
+
+    Ok(records)
+}
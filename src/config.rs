@@ -2,6 +2,8 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::money::Lamports;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     // PumpSwap gRPC configuration
@@ -18,15 +20,15 @@ pub struct Config {
     
     // Sniper configuration
     pub target_tokens: Vec<String>,
-    pub min_liquidity: f64,
+    pub min_liquidity: Lamports,
     pub max_slippage: f64,
-    pub snipe_amount: f64,
+    pub snipe_amount: Lamports,
     pub max_gas_price: u64,
-    
+
     // MEV configuration
     pub enable_mev: bool,
     pub mev_strategies: Vec<String>,
-    pub max_mev_profit: f64,
+    pub max_mev_profit: Lamports,
     
     // Confirmation service
     pub confirmation_service: String, // "jito" or "nozomi"
@@ -34,12 +36,19 @@ pub struct Config {
     // Jito configuration
     pub jito_url: String,
     pub jito_tip_account: String,
+    pub jito_tip_accounts: Vec<String>,
     pub jito_tip_amount: u64,
+    pub identity_keypair_path: Option<String>,
     
     // Nozomi configuration
     pub nozomi_url: String,
     pub nozomi_api_key: Option<String>,
-    
+
+    // Which path(s) NozomiManager submits through: "nozomi" (relay only),
+    // "tpu" (direct-to-leader QUIC only), or "both" (relay, with a direct
+    // QUIC fan-out raced alongside it)
+    pub submission_backend: String,
+
     // Performance settings
     pub max_concurrent_trades: usize,
     pub transaction_timeout: u64,
@@ -52,16 +61,90 @@ pub struct Config {
     pub log_level: String,
     
     // Risk management
-    pub max_daily_loss: f64,
-    pub max_position_size: f64,
+    pub max_daily_loss: Lamports,
+    pub max_position_size: Lamports,
     pub stop_loss_percentage: f64,
     pub take_profit_percentage: f64,
-    
+    // Maintenance margin ratio (e.g. 0.05 = 5%) used by `liquidation_price`
+    // to flag MEV opportunities whose resulting position sits close to
+    // liquidation.
+    pub maintenance_margin: f64,
+
     // Advanced settings
     pub priority_fee_multiplier: f64,
     pub bundle_timeout: u64,
     pub max_bundle_size: usize,
     pub enable_frontrunning_protection: bool,
+
+    // Direct TPU/QUIC submission
+    pub enable_direct_tpu: bool,
+    pub tpu_fanout: usize,
+    pub tpu_quic_connect_timeout_ms: u64,
+
+    // PumpSwap gRPC relay vs. direct-TPU submission for `submit_transaction_racing`
+    pub tpu_submission_mode: String, // "relay", "direct", or "race"
+
+    // Priority fee estimation
+    pub priority_fee_percentile: f64,
+    pub max_priority_fee: Option<u64>,
+
+    // Transaction replayer
+    pub max_replay_count: u32,
+    pub replay_interval_ms: u64,
+
+    // Trade persistence
+    pub trade_db_path: String,
+
+    // Jupiter quoting
+    pub jupiter_api_url: String,
+    pub jupiter_quote_timeout_ms: u64,
+
+    // Execution work-queue
+    pub trade_queue_capacity: usize,
+
+    // Pre-submit simulation guard
+    pub enable_simulation_guard: bool,
+
+    // JSON-RPC control server
+    pub enable_control_server: bool,
+    pub control_server_port: u16,
+
+    // Position rebalancing / exits
+    pub profit_ladder: Vec<ProfitLadderRung>,
+    pub trailing_stop_percentage: f64,
+    pub position_timeout_secs: u64,
+
+    // gRPC stream reconnection
+    pub stream_reconnect_base_delay_ms: u64,
+    pub stream_reconnect_max_delay_ms: u64,
+
+    // Per-token error tracking / cooldown
+    pub error_cooldown_base_ms: u64,
+    pub error_cooldown_max_ms: u64,
+
+    // Concurrent MEV candidate polling
+    pub mev_poll_interval_ms: u64,
+    pub mev_opportunity_staleness_ms: u64,
+
+    // Pre-submit state guard
+    pub max_reserve_drift_bps: u64,
+    pub require_fresh_state: bool,
+
+    // Multi-source price oracle aggregation
+    pub max_oracle_staleness_secs: u64,
+    pub oracle_divergence_bps: u64,
+    pub oracle_source_priority: Vec<String>,
+    pub enable_external_amm_oracle: bool,
+
+    // Streaming confirmation (Yellowstone/Geyser gRPC)
+    pub geyser_grpc_url: String,
+    pub geyser_commitment: String, // "confirmed" or "finalized"
+    pub enable_streaming_confirmation: bool,
+
+    // Durable submission tracking (requires the `postgres-store` feature
+    // when `submission_store_backend` is "postgres")
+    pub submission_store_backend: String, // "memory" or "postgres"
+    pub postgres_url: Option<String>,
 }
 
 impl Default for Config {
@@ -77,24 +160,37 @@ impl Default for Config {
             wallet_address: None,
             
             target_tokens: vec![],
-            min_liquidity: 10.0,
+            min_liquidity: Lamports::from_sol(10.0),
             max_slippage: 5.0,
-            snipe_amount: 1.0,
+            snipe_amount: Lamports::from_sol(1.0),
             max_gas_price: 1000000,
-            
+
             enable_mev: true,
             mev_strategies: vec!["arbitrage".to_string(), "frontrun".to_string()],
-            max_mev_profit: 1000.0,
+            max_mev_profit: Lamports::from_sol(1000.0),
             
             confirmation_service: "jito".to_string(),
             
             jito_url: "https://mainnet.block-engine.jito.wtf".to_string(),
             jito_tip_account: "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY".to_string(),
+            jito_tip_accounts: vec![
+                "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY".to_string(),
+                "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5".to_string(),
+                "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe".to_string(),
+                "ADuUkR4vqLUMWXxW9gH6yJPL5K2dc7qj3qKdDqwqaVpc".to_string(),
+                "DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh".to_string(),
+                "ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49".to_string(),
+                "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL".to_string(),
+                "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT".to_string(),
+            ],
             jito_tip_amount: 10000,
+            identity_keypair_path: None,
             
             nozomi_url: "https://api.nozomi.com".to_string(),
             nozomi_api_key: None,
-            
+
+            submission_backend: "nozomi".to_string(),
+
             max_concurrent_trades: 5,
             transaction_timeout: 30,
             retry_attempts: 3,
@@ -104,15 +200,76 @@ impl Default for Config {
             metrics_port: 9090,
             log_level: "info".to_string(),
             
-            max_daily_loss: 100.0,
-            max_position_size: 10.0,
+            max_daily_loss: Lamports::from_sol(100.0),
+            max_position_size: Lamports::from_sol(10.0),
             stop_loss_percentage: 10.0,
             take_profit_percentage: 50.0,
-            
+            maintenance_margin: 0.05,
+
+
             priority_fee_multiplier: 1.5,
             bundle_timeout: 5000,
             max_bundle_size: 10,
             enable_frontrunning_protection: true,
+
+            enable_direct_tpu: false,
+            tpu_fanout: 4,
+            tpu_quic_connect_timeout_ms: 250,
+
+            tpu_submission_mode: "relay".to_string(),
+
+            priority_fee_percentile: 75.0,
+            max_priority_fee: None,
+
+            max_replay_count: 20,
+            replay_interval_ms: 75,
+
+            trade_db_path: "trades.db".to_string(),
+
+            jupiter_api_url: "https://quote-api.jup.ag/v6".to_string(),
+            jupiter_quote_timeout_ms: 500,
+
+            trade_queue_capacity: 256,
+
+            enable_simulation_guard: true,
+
+            enable_control_server: true,
+            control_server_port: 9091,
+
+            profit_ladder: vec![
+                ProfitLadderRung { gain_percentage: 100.0, sell_fraction: 0.5 },
+                ProfitLadderRung { gain_percentage: 300.0, sell_fraction: 0.25 },
+            ],
+            trailing_stop_percentage: 20.0,
+            position_timeout_secs: 3600,
+
+            stream_reconnect_base_delay_ms: 500,
+            stream_reconnect_max_delay_ms: 30000,
+
+            error_cooldown_base_ms: 5_000,
+            error_cooldown_max_ms: 600_000,
+
+            mev_poll_interval_ms: 250,
+            mev_opportunity_staleness_ms: 500,
+
+            max_reserve_drift_bps: 200, // 2%
+            require_fresh_state: true,
+
+            max_oracle_staleness_secs: 10,
+            oracle_divergence_bps: 300, // 3%
+            oracle_source_priority: vec![
+                "pumpswap_grpc".to_string(),
+                "solana_rpc_pool".to_string(),
+                "external_amm".to_string(),
+            ],
+            enable_external_amm_oracle: false,
+
+            geyser_grpc_url: "https://geyser.pumpswap.fun:443".to_string(),
+            geyser_commitment: "confirmed".to_string(),
+            enable_streaming_confirmation: true,
+
+            submission_store_backend: "memory".to_string(),
+            postgres_url: None,
         }
     }
 }
@@ -128,7 +285,7 @@ impl Config {
             let default_config = Config::default();
             let content = toml::to_string_pretty(&default_config)?;
             std::fs::write(path, content)?;
-            log::info!("Created default configuration file: {}", path);
+            tracing::info!("Created default configuration file: {}", path);
             Ok(default_config)
         }
     }
@@ -148,25 +305,62 @@ impl Config {
             return Err(anyhow::anyhow!("At least one target token is required"));
         }
         
-        if self.min_liquidity <= 0.0 {
+        if self.min_liquidity == Lamports::ZERO {
             return Err(anyhow::anyhow!("Minimum liquidity must be positive"));
         }
-        
+
         if self.max_slippage <= 0.0 || self.max_slippage > 100.0 {
             return Err(anyhow::anyhow!("Maximum slippage must be between 0 and 100"));
         }
-        
-        if self.snipe_amount <= 0.0 {
+
+        if self.snipe_amount == Lamports::ZERO {
             return Err(anyhow::anyhow!("Snipe amount must be positive"));
         }
-        
-        if !["jito", "nozomi"].contains(&self.confirmation_service.as_str()) {
-            return Err(anyhow::anyhow!("Confirmation service must be 'jito' or 'nozomi'"));
+
+        if self.maintenance_margin < 0.0 || self.maintenance_margin >= 1.0 {
+            return Err(anyhow::anyhow!("Maintenance margin must be between 0 and 1"));
         }
-        
+
+
+        if !["jito", "nozomi", "tpu"].contains(&self.confirmation_service.as_str()) {
+            return Err(anyhow::anyhow!("Confirmation service must be 'jito', 'nozomi', or 'tpu'"));
+        }
+
+        if !["relay", "direct", "race"].contains(&self.tpu_submission_mode.as_str()) {
+            return Err(anyhow::anyhow!("TPU submission mode must be 'relay', 'direct', or 'race'"));
+        }
+
+        if !["nozomi", "tpu", "both"].contains(&self.submission_backend.as_str()) {
+            return Err(anyhow::anyhow!("submission_backend must be 'nozomi', 'tpu', or 'both'"));
+        }
+
+        if self.max_reserve_drift_bps > 10_000 {
+            return Err(anyhow::anyhow!("max_reserve_drift_bps must be at most 10000 (100%)"));
+        }
+
+        if self.oracle_divergence_bps > 10_000 {
+            return Err(anyhow::anyhow!("oracle_divergence_bps must be at most 10000 (100%)"));
+        }
+
+        if self.oracle_source_priority.is_empty() {
+            return Err(anyhow::anyhow!("oracle_source_priority must list at least one source"));
+        }
+
+        if !["confirmed", "finalized"].contains(&self.geyser_commitment.as_str()) {
+            return Err(anyhow::anyhow!("geyser_commitment must be 'confirmed' or 'finalized'"));
+        }
+
+        if !["memory", "postgres"].contains(&self.submission_store_backend.as_str()) {
+            return Err(anyhow::anyhow!("submission_store_backend must be 'memory' or 'postgres'"));
+        }
+
+        if self.submission_store_backend == "postgres" && self.postgres_url.is_none() {
+            return Err(anyhow::anyhow!("postgres_url is required when submission_store_backend is 'postgres'"));
+        }
+
         Ok(())
     }
-    
+
     pub fn get_mev_strategies(&self) -> Vec<MEVStrategy> {
         self.mev_strategies
             .iter()
@@ -180,6 +374,30 @@ impl Config {
             })
             .collect()
     }
+
+    /// `oracle_source_priority` parsed into `OracleSource`s, in the order the
+    /// aggregator should prefer them when more than one reading is fresh.
+    /// Unrecognized entries are dropped rather than rejected at parse time,
+    /// the same way `get_mev_strategies` tolerates an unknown strategy name.
+    pub fn get_oracle_source_priority(&self) -> Vec<OracleSource> {
+        self.oracle_source_priority
+            .iter()
+            .filter_map(|s| match s.as_str() {
+                "pumpswap_grpc" => Some(OracleSource::PumpSwapGrpc),
+                "solana_rpc_pool" => Some(OracleSource::SolanaRpcPool),
+                "external_amm" => Some(OracleSource::ExternalAmm),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// One rung of a take-profit ladder: once a position is up `gain_percentage`
+/// from its entry price, sell `sell_fraction` of the tokens it started with.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ProfitLadderRung {
+    pub gain_percentage: f64,
+    pub sell_fraction: f64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -202,3 +420,26 @@ impl MEVStrategy {
         }
     }
 }
+
+/// One feed `OracleAggregator` can draw a price from. `PumpSwapGrpc` is the
+/// push-based stream that already drives most decisions; `SolanaRpcPool` is
+/// a direct on-chain reserve read (the same account `StateGuard` captures);
+/// `ExternalAmm` is a slot for a second venue's read (e.g. a Raydium CLMM
+/// quote) and only participates in consensus when a caller actually records
+/// a reading for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OracleSource {
+    PumpSwapGrpc,
+    SolanaRpcPool,
+    ExternalAmm,
+}
+
+impl OracleSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OracleSource::PumpSwapGrpc => "pumpswap_grpc",
+            OracleSource::SolanaRpcPool => "solana_rpc_pool",
+            OracleSource::ExternalAmm => "external_amm",
+        }
+    }
+}
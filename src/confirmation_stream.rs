@@ -0,0 +1,155 @@
+use anyhow::Result;
+use dashmap::DashMap;
+use solana_sdk::signature::Signature;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{oneshot, RwLock};
+use tonic::transport::Endpoint;
+use tracing::{debug, info, warn};
+
+use crate::config::Config;
+use crate::proto::geyser::geyser_client::GeyserClient;
+use crate::proto::geyser::subscribe_update::UpdateOneof;
+use crate::proto::geyser::{CommitmentLevel, SubscribeRequest, SubscribeRequestFilterTransactions};
+
+/// Subscribes once to a Yellowstone/Geyser transaction stream and resolves
+/// per-signature confirmation from it, so `NozomiManager` no longer has to
+/// busy-poll `get_submission_status` for every in-flight submission. Falls
+/// back to the caller's own polling whenever the stream is disconnected,
+/// rather than trying to buffer signatures until a reconnect lands.
+pub struct ConfirmationSubscriber {
+    config: Arc<RwLock<Config>>,
+    waiters: Arc<DashMap<Signature, oneshot::Sender<bool>>>,
+    connected: Arc<AtomicBool>,
+}
+
+impl ConfirmationSubscriber {
+    pub fn new(config: Arc<RwLock<Config>>) -> Self {
+        Self {
+            config,
+            waiters: Arc::new(DashMap::new()),
+            connected: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Whether the Geyser stream is currently connected. Callers should fall
+    /// back to polling immediately when this is `false` instead of waiting
+    /// on a oneshot that will never fire.
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    /// Registers interest in `signature` and resolves once it lands in a
+    /// block at the configured commitment level, or once `timeout` elapses.
+    /// Returns `Err` if the stream isn't connected at all, so the caller can
+    /// fall back to polling without waiting out the full timeout first.
+    pub async fn wait_for_signature(&self, signature: Signature, timeout: Duration) -> Result<bool> {
+        if !self.is_connected() {
+            return Err(anyhow::anyhow!("Geyser stream is disconnected"));
+        }
+
+        let (tx, rx) = oneshot::channel();
+        self.waiters.insert(signature, tx);
+
+        let result = tokio::time::timeout(timeout, rx).await;
+        self.waiters.remove(&signature);
+
+        match result {
+            Ok(Ok(confirmed)) => Ok(confirmed),
+            Ok(Err(_)) => Err(anyhow::anyhow!("Geyser stream closed while waiting for {}", signature)),
+            Err(_) => Ok(false), // timed out, not disconnected
+        }
+    }
+
+    /// Spawn the background connect/reconnect loop. Reuses the same
+    /// base/max exponential backoff knobs as the PumpSwap gRPC stream
+    /// reconnection (`stream_reconnect_*_ms`) rather than introducing a
+    /// parallel set of settings for a second gRPC source.
+    pub fn start(&self) {
+        let config = self.config.clone();
+        let waiters = self.waiters.clone();
+        let connected = self.connected.clone();
+
+        tokio::spawn(async move {
+            let mut backoff_ms = {
+                let config_guard = config.read().await;
+                config_guard.stream_reconnect_base_delay_ms
+            };
+
+            loop {
+                let (url, commitment) = {
+                    let config_guard = config.read().await;
+                    (config_guard.geyser_grpc_url.clone(), config_guard.geyser_commitment.clone())
+                };
+
+                match Self::run_stream(&url, &commitment, &waiters, &connected).await {
+                    Ok(()) => {
+                        info!("Geyser confirmation stream ended cleanly, reconnecting");
+                    }
+                    Err(e) => {
+                        warn!("Geyser confirmation stream error: {}", e);
+                    }
+                }
+
+                connected.store(false, Ordering::Relaxed);
+                Self::fail_all_waiters(&waiters);
+
+                let max_backoff_ms = config.read().await.stream_reconnect_max_delay_ms;
+                tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+                backoff_ms = (backoff_ms * 2).min(max_backoff_ms);
+            }
+        });
+    }
+
+    async fn run_stream(
+        url: &str,
+        commitment: &str,
+        waiters: &Arc<DashMap<Signature, oneshot::Sender<bool>>>,
+        connected: &Arc<AtomicBool>,
+    ) -> Result<()> {
+        let endpoint = Endpoint::from_shared(url.to_string())?;
+        let channel = endpoint.connect().await?;
+        let mut client = GeyserClient::new(channel);
+
+        let commitment_level = match commitment {
+            "finalized" => CommitmentLevel::Finalized,
+            _ => CommitmentLevel::Confirmed,
+        };
+
+        let request = SubscribeRequest {
+            transactions: Some(SubscribeRequestFilterTransactions {
+                vote: Some(false),
+                failed: Some(true),
+            }),
+            commitment: commitment_level.into(),
+        };
+
+        let mut stream = client.subscribe(request).await?.into_inner();
+        connected.store(true, Ordering::Relaxed);
+        info!("Connected to Geyser confirmation stream at {}", url);
+
+        while let Some(update) = stream.message().await? {
+            let Some(UpdateOneof::Transaction(tx_update)) = update.update_oneof else {
+                continue;
+            };
+
+            let Ok(signature) = tx_update.signature.parse::<Signature>() else {
+                continue;
+            };
+
+            if let Some((_, sender)) = waiters.remove(&signature) {
+                debug!("Signature {} confirmed via Geyser stream", signature);
+                let _ = sender.send(!tx_update.is_failed);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Drops every pending sender, so each waiter's oneshot errors and
+    /// `wait_for_signature` returns `Err`, sending the caller to polling.
+    fn fail_all_waiters(waiters: &Arc<DashMap<Signature, oneshot::Sender<bool>>>) {
+        waiters.clear();
+    }
+}
@@ -0,0 +1,132 @@
+use dashmap::DashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::config::Config;
+use crate::monitoring::Monitoring;
+
+/// Consecutive-failure state for one (token_address, operation_kind) pair.
+#[derive(Debug, Clone)]
+struct ErrorRecord {
+    consecutive_failures: u32,
+    last_failure_at: u64,
+    last_error: String,
+}
+
+/// Per-token/operation failure tracker with an exponential-backoff cooldown,
+/// ported from the liquidator bot's `ErrorTracking` idea: a token that keeps
+/// reverting (failed sims, no liquidity, blacklisted mint) stops getting
+/// hammered on every new listing/price tick instead of burning a queue slot
+/// and a wasted quote/sim on every attempt.
+pub struct ErrorTracker {
+    config: Arc<RwLock<Config>>,
+    records: DashMap<(String, String), ErrorRecord>,
+}
+
+impl ErrorTracker {
+    pub fn new(config: Arc<RwLock<Config>>) -> Self {
+        Self {
+            config,
+            records: DashMap::new(),
+        }
+    }
+
+    fn key(token: &str, operation: &str) -> (String, String) {
+        (token.to_string(), operation.to_string())
+    }
+
+    /// Record a failed attempt, bumping the consecutive-failure streak.
+    pub fn record_failure(&self, token: &str, operation: &str, error: &str) {
+        let mut entry = self
+            .records
+            .entry(Self::key(token, operation))
+            .or_insert_with(|| ErrorRecord {
+                consecutive_failures: 0,
+                last_failure_at: 0,
+                last_error: String::new(),
+            });
+
+        entry.consecutive_failures += 1;
+        entry.last_failure_at = crate::utils::get_timestamp_ms();
+        entry.last_error = error.to_string();
+
+        Monitoring::update_token_failure_count(token, operation, entry.consecutive_failures);
+    }
+
+    /// Reset the streak after a successful attempt.
+    pub fn record_success(&self, token: &str, operation: &str) {
+        if let Some(mut entry) = self.records.get_mut(&Self::key(token, operation)) {
+            entry.consecutive_failures = 0;
+        }
+        Monitoring::update_token_failure_count(token, operation, 0);
+    }
+
+    /// Whether `token`/`operation` is currently inside its cooldown window.
+    /// Cooldown length grows as `min(base * 2^(failures - 1), max)`, so a
+    /// token that fails once backs off briefly but one that keeps failing
+    /// gets pushed out to the configured ceiling.
+    pub async fn should_skip(&self, token: &str, operation: &str) -> bool {
+        let Some(entry) = self.records.get(&Self::key(token, operation)) else {
+            return false;
+        };
+
+        if entry.consecutive_failures == 0 {
+            return false;
+        }
+
+        let (base_ms, max_ms) = {
+            let config_guard = self.config.read().await;
+            (config_guard.error_cooldown_base_ms, config_guard.error_cooldown_max_ms)
+        };
+
+        let cooldown_ms = base_ms
+            .saturating_mul(1u64 << (entry.consecutive_failures - 1).min(31))
+            .min(max_ms);
+
+        let elapsed_ms = crate::utils::get_timestamp_ms().saturating_sub(entry.last_failure_at);
+
+        elapsed_ms < cooldown_ms
+    }
+
+    /// Number of (token, operation) pairs currently inside their cooldown
+    /// window; published as a metric so an operator can see at a glance how
+    /// much of the target set is being suppressed.
+    pub async fn suppressed_count(&self) -> usize {
+        let (base_ms, max_ms) = {
+            let config_guard = self.config.read().await;
+            (config_guard.error_cooldown_base_ms, config_guard.error_cooldown_max_ms)
+        };
+        let now = crate::utils::get_timestamp_ms();
+
+        self.records
+            .iter()
+            .filter(|entry| {
+                if entry.consecutive_failures == 0 {
+                    return false;
+                }
+                let cooldown_ms = base_ms
+                    .saturating_mul(1u64 << (entry.consecutive_failures - 1).min(31))
+                    .min(max_ms);
+                now.saturating_sub(entry.last_failure_at) < cooldown_ms
+            })
+            .count()
+    }
+
+    /// Fraction (0.0-100.0) of tracked (token, operation) pairs that are
+    /// currently mid-streak (at least one unresolved failure), used as a
+    /// real stand-in for `Monitoring::get_error_rate`'s previous hardcoded
+    /// value.
+    pub fn error_rate(&self) -> f64 {
+        if self.records.is_empty() {
+            return 0.0;
+        }
+
+        let failing = self
+            .records
+            .iter()
+            .filter(|entry| entry.consecutive_failures > 0)
+            .count();
+
+        (failing as f64 / self.records.len() as f64) * 100.0
+    }
+}
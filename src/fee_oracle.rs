@@ -0,0 +1,81 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
+
+use crate::config::Config;
+
+/// EIP-1559's per-block adjustment denominator: a single slot's observation
+/// can move the base fee by at most `1/EIP1559_DENOMINATOR`.
+const EIP1559_DENOMINATOR: f64 = 8.0;
+
+/// Matches `calculate_optimal_gas_price`'s existing floor.
+const MIN_BASE_FEE_LAMPORTS: u64 = 5000; // 0.000005 SOL
+
+/// Number of recent slots averaged together before applying the recurrence,
+/// so one unusually quiet or unusually congested slot can't whipsaw the fee.
+const FULLNESS_WINDOW: usize = 20;
+
+/// Adaptive base-fee tracker for priority fees, replacing the naive
+/// `calculate_optimal_gas_price` static-multiply with an EIP-1559-style
+/// recurrence: `base_fee_next = base_fee * (1 + (gas_used - gas_target) /
+/// gas_target / denominator)`. This rises during sustained contention and
+/// decays back down once blocks stop being full, rather than reacting to a
+/// single congestion reading.
+pub struct FeeOracle {
+    base_fee: AtomicU64,
+    fullness_window: Mutex<VecDeque<f64>>,
+    config: Arc<RwLock<Config>>,
+}
+
+impl FeeOracle {
+    pub fn new(config: Arc<RwLock<Config>>) -> Self {
+        Self {
+            base_fee: AtomicU64::new(MIN_BASE_FEE_LAMPORTS),
+            fullness_window: Mutex::new(VecDeque::with_capacity(FULLNESS_WINDOW)),
+            config,
+        }
+    }
+
+    pub fn base_fee(&self) -> u64 {
+        self.base_fee.load(Ordering::Relaxed)
+    }
+
+    /// Feed in one observed slot's contention (e.g. recent compute-unit
+    /// consumption, or a prioritization-fee percentile) against `gas_target`,
+    /// updating the running base fee via the EIP-1559 recurrence.
+    pub async fn observe_slot(&self, gas_used: u64, gas_target: u64) {
+        if gas_target == 0 {
+            return;
+        }
+
+        let fullness = gas_used as f64 / gas_target as f64;
+        let smoothed_fullness = {
+            let mut window = self.fullness_window.lock().await;
+            if window.len() == FULLNESS_WINDOW {
+                window.pop_front();
+            }
+            window.push_back(fullness);
+            window.iter().sum::<f64>() / window.len() as f64
+        };
+
+        let step = ((smoothed_fullness - 1.0) / EIP1559_DENOMINATOR)
+            .clamp(-1.0 / EIP1559_DENOMINATOR, 1.0 / EIP1559_DENOMINATOR);
+
+        let current = self.base_fee.load(Ordering::Relaxed);
+        let next = (current as f64 * (1.0 + step)).round() as u64;
+        self.base_fee.store(next.max(MIN_BASE_FEE_LAMPORTS), Ordering::Relaxed);
+    }
+
+    /// Suggested priority fee in lamports: the current base plus a surcharge
+    /// of `urgency * priority_fee_multiplier` times that base, where
+    /// `urgency` is a caller-supplied 0.0-1.0+ knob (e.g. how close a listing
+    /// is to going stale) and `priority_fee_multiplier` is `Config`'s
+    /// existing elasticity multiplier.
+    pub async fn suggested_priority_fee(&self, urgency: f64) -> u64 {
+        let elasticity_multiplier = self.config.read().await.priority_fee_multiplier;
+        let base = self.base_fee();
+        let surcharge = (urgency * elasticity_multiplier * base as f64).max(0.0).round() as u64;
+        base.saturating_add(surcharge)
+    }
+}
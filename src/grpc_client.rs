@@ -1,13 +1,23 @@
 use anyhow::Result;
-use log::{debug, error, info, warn};
+use tracing::{debug, error, info, warn};
+use solana_sdk::transaction::Transaction;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{broadcast, RwLock};
+use tokio::task::JoinHandle;
 use tonic::transport::{Channel, Endpoint};
 use tonic::Request;
 
 use crate::config::Config;
+use crate::monitoring::Monitoring;
 use crate::proto::pumpswap::pumpswap_service_client::PumpSwapServiceClient;
 use crate::proto::pumpswap::*;
+use crate::tpu_client::TpuClient;
+
+/// Capacity of the broadcast channels fanning out listings/price updates to
+/// every subscriber; a slow subscriber that falls this far behind starts
+/// missing messages rather than stalling the others.
+const BROADCAST_CAPACITY: usize = 1024;
 
 pub struct PumpSwapGrpcClient {
     client: PumpSwapServiceClient<Channel>,
@@ -100,7 +110,7 @@ impl PumpSwapGrpcClient {
         let config_guard = self.config.read().await;
         let request = MEVRequest {
             token_addresses,
-            min_liquidity: (config_guard.min_liquidity * 1e9) as u64, // Convert SOL to lamports
+            min_liquidity: config_guard.min_liquidity.0,
             max_slippage: config_guard.max_slippage,
             max_gas_price: config_guard.max_gas_price,
         };
@@ -132,37 +142,209 @@ impl PumpSwapGrpcClient {
         
         Ok(response.into_inner())
     }
+
+    /// Submits `transaction` per `Config::tpu_submission_mode`: `"relay"` forwards it through the
+    /// PumpSwap gRPC backend exactly like [`submit_transaction`](Self::submit_transaction),
+    /// `"direct"` sends it straight to the upcoming leaders over QUIC via `tpu_client`, and
+    /// `"race"` fires both concurrently and returns the result of whichever send call completes
+    /// first, even if that result is an error; the other send is dropped.
+    pub async fn submit_transaction_racing(
+        &mut self,
+        transaction: &Transaction,
+        tpu_client: &TpuClient,
+    ) -> Result<String> {
+        let (mode, tpu_fanout) = {
+            let config_guard = self.config.read().await;
+            (config_guard.tpu_submission_mode.clone(), config_guard.tpu_fanout)
+        };
+
+        let direct_submission_id = || {
+            format!(
+                "tpu_direct_{}",
+                transaction.signatures.first().map(|s| s.to_string()).unwrap_or_default()
+            )
+        };
+
+        match mode.as_str() {
+            "direct" => {
+                tpu_client.send_transaction(transaction, tpu_fanout).await?;
+                Ok(direct_submission_id())
+            }
+            "race" => {
+                let transaction_data = base64::encode(bincode::serialize(transaction)?);
+                tokio::select! {
+                    relay_result = self.submit_transaction(&transaction_data) => {
+                        relay_result.map(|response| response.submission_id)
+                    }
+                    direct_result = tpu_client.send_transaction(transaction, tpu_fanout) => {
+                        direct_result.map(|()| direct_submission_id())
+                    }
+                }
+            }
+            _ => {
+                let transaction_data = base64::encode(bincode::serialize(transaction)?);
+                Ok(self.submit_transaction(&transaction_data).await?.submission_id)
+            }
+        }
+    }
+}
+
+/// Sleeps for an exponentially-growing, jittered, capped backoff before the
+/// next reconnect attempt. `attempt` is the number of consecutive failures
+/// seen so far (0-indexed); jitter is applied as a uniform random fraction
+/// of the computed delay so that many subscribers/processes reconnecting
+/// after the same outage don't all hammer the endpoint in lockstep.
+async fn reconnect_backoff(config: &Arc<RwLock<Config>>, attempt: u32) {
+    let (base_ms, max_ms) = {
+        let config_guard = config.read().await;
+        (
+            config_guard.stream_reconnect_base_delay_ms,
+            config_guard.stream_reconnect_max_delay_ms,
+        )
+    };
+
+    let exp_ms = base_ms.saturating_mul(1u64 << attempt.min(16));
+    let capped_ms = exp_ms.min(max_ms).max(base_ms);
+
+    let jittered_ms = {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        rng.gen_range((capped_ms / 2)..=capped_ms)
+    };
+
+    tokio::time::sleep(Duration::from_millis(jittered_ms)).await;
 }
 
+/// Wraps [`PumpSwapGrpcClient::stream_new_listings`] with automatic
+/// reconnection and fans the upstream stream out to every subscriber over a
+/// `tokio::sync::broadcast` channel, so the MEV detector, price tracker, and
+/// logger can each follow new listings without opening their own gRPC
+/// stream. A dropped connection (stream end or transport error) is retried
+/// with capped exponential backoff, reconnecting the channel and
+/// re-attaching the auth header each time.
 pub struct TokenListingStream {
-    client: PumpSwapGrpcClient,
+    tx: broadcast::Sender<TokenListing>,
     target_tokens: Vec<String>,
+    _supervisor: JoinHandle<()>,
 }
 
 impl TokenListingStream {
     pub fn new(config: Arc<RwLock<Config>>) -> Self {
+        let (tx, _rx) = broadcast::channel(BROADCAST_CAPACITY);
+        let target_tokens = {
+            let config = config.clone();
+            // `target_tokens` rarely changes at runtime, so a best-effort
+            // snapshot taken at construction (falling back to "all tokens"
+            // if the config lock can't be acquired synchronously) matches
+            // how every other consumer of this field treats it.
+            match config.try_read() {
+                Ok(guard) => guard.target_tokens.clone(),
+                Err(_) => Vec::new(),
+            }
+        };
+
+        let supervisor = {
+            let tx = tx.clone();
+            let config = config.clone();
+            tokio::spawn(async move {
+                Self::run(config, tx).await;
+            })
+        };
+
         Self {
-            client: PumpSwapGrpcClient::new(config.clone()).await.unwrap(),
-            target_tokens: config.read().await.target_tokens.clone(),
+            tx,
+            target_tokens,
+            _supervisor: supervisor,
         }
     }
-    
-    pub async fn start_streaming<F>(&mut self, mut callback: F) -> Result<()>
+
+    /// Subscribe to the fanned-out listing stream; each subscriber gets its
+    /// own independent receiver and a slow one only risks lagging itself.
+    pub fn subscribe(&self) -> broadcast::Receiver<TokenListing> {
+        self.tx.subscribe()
+    }
+
+    async fn run(config: Arc<RwLock<Config>>, tx: broadcast::Sender<TokenListing>) {
+        let mut attempt = 0u32;
+
+        loop {
+            Monitoring::update_listing_stream_connected(false);
+
+            let mut client = match PumpSwapGrpcClient::new(config.clone()).await {
+                Ok(client) => client,
+                Err(e) => {
+                    warn!("Failed to connect listing stream client: {}", e);
+                    reconnect_backoff(&config, attempt).await;
+                    attempt = attempt.saturating_add(1);
+                    continue;
+                }
+            };
+
+            let mut stream = match client.stream_new_listings().await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    warn!("Failed to open listing stream: {}", e);
+                    reconnect_backoff(&config, attempt).await;
+                    attempt = attempt.saturating_add(1);
+                    continue;
+                }
+            };
+
+            info!("Token listing stream connected");
+            Monitoring::update_listing_stream_connected(true);
+            attempt = 0;
+
+            loop {
+                match stream.message().await {
+                    Ok(Some(listing)) => {
+                        debug!("Received new listing: {} ({})", listing.token_symbol, listing.token_address);
+                        // Errors here just mean no subscribers are currently
+                        // listening; nothing to reconnect or log loudly for.
+                        let _ = tx.send(listing);
+                    }
+                    Ok(None) => {
+                        warn!("Token listing stream ended, reconnecting");
+                        break;
+                    }
+                    Err(e) => {
+                        warn!("Token listing stream error, reconnecting: {}", e);
+                        break;
+                    }
+                }
+            }
+
+            Monitoring::update_listing_stream_connected(false);
+            Monitoring::record_listing_stream_reconnect();
+            reconnect_backoff(&config, attempt).await;
+            attempt = attempt.saturating_add(1);
+        }
+    }
+
+    pub async fn start_streaming<F>(&self, mut callback: F) -> Result<()>
     where
         F: FnMut(TokenListing) -> Result<bool>, // Return false to stop streaming
     {
-        let mut stream = self.client.stream_new_listings().await?;
-        
+        let mut rx = self.subscribe();
+
         info!("Started streaming new token listings");
-        
-        while let Some(listing) = stream.message().await? {
-            debug!("Received new listing: {} ({})", listing.token_symbol, listing.token_address);
-            
+
+        loop {
+            let listing = match rx.recv().await {
+                Ok(listing) => listing,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("Listing stream subscriber lagged, dropped {} messages", skipped);
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    info!("Token listing stream closed");
+                    break;
+                }
+            };
+
             // Check if this token is in our target list or if we're monitoring all tokens
             if self.target_tokens.is_empty() || self.target_tokens.contains(&listing.token_address) {
                 info!("Processing new listing: {} ({})", listing.token_symbol, listing.token_address);
-                
-                // Call the callback function
+
                 match callback(listing) {
                     Ok(continue_streaming) => {
                         if !continue_streaming {
@@ -177,38 +359,115 @@ impl TokenListingStream {
                 }
             }
         }
-        
-        info!("Token listing stream ended");
+
         Ok(())
     }
 }
 
+/// Wraps [`PumpSwapGrpcClient::stream_price_updates`] with the same
+/// reconnect-and-fan-out behavior as [`TokenListingStream`].
 pub struct PriceUpdateStream {
-    client: PumpSwapGrpcClient,
-    token_addresses: Vec<String>,
+    tx: broadcast::Sender<PriceUpdate>,
+    _supervisor: JoinHandle<()>,
 }
 
 impl PriceUpdateStream {
     pub async fn new(config: Arc<RwLock<Config>>, token_addresses: Vec<String>) -> Result<Self> {
-        let client = PumpSwapGrpcClient::new(config).await?;
+        let (tx, _rx) = broadcast::channel(BROADCAST_CAPACITY);
+
+        let supervisor = {
+            let tx = tx.clone();
+            let config = config.clone();
+            tokio::spawn(async move {
+                Self::run(config, token_addresses, tx).await;
+            })
+        };
+
         Ok(Self {
-            client,
-            token_addresses,
+            tx,
+            _supervisor: supervisor,
         })
     }
-    
-    pub async fn start_streaming<F>(&mut self, mut callback: F) -> Result<()>
+
+    pub fn subscribe(&self) -> broadcast::Receiver<PriceUpdate> {
+        self.tx.subscribe()
+    }
+
+    async fn run(config: Arc<RwLock<Config>>, token_addresses: Vec<String>, tx: broadcast::Sender<PriceUpdate>) {
+        let mut attempt = 0u32;
+
+        loop {
+            Monitoring::update_price_stream_connected(false);
+
+            let mut client = match PumpSwapGrpcClient::new(config.clone()).await {
+                Ok(client) => client,
+                Err(e) => {
+                    warn!("Failed to connect price stream client: {}", e);
+                    reconnect_backoff(&config, attempt).await;
+                    attempt = attempt.saturating_add(1);
+                    continue;
+                }
+            };
+
+            let mut stream = match client.stream_price_updates(token_addresses.clone()).await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    warn!("Failed to open price stream: {}", e);
+                    reconnect_backoff(&config, attempt).await;
+                    attempt = attempt.saturating_add(1);
+                    continue;
+                }
+            };
+
+            info!("Price update stream connected for {} tokens", token_addresses.len());
+            Monitoring::update_price_stream_connected(true);
+            attempt = 0;
+
+            loop {
+                match stream.message().await {
+                    Ok(Some(update)) => {
+                        debug!("Received price update for {}: ${:.6}", update.token_address, update.price_usd);
+                        let _ = tx.send(update);
+                    }
+                    Ok(None) => {
+                        warn!("Price update stream ended, reconnecting");
+                        break;
+                    }
+                    Err(e) => {
+                        warn!("Price update stream error, reconnecting: {}", e);
+                        break;
+                    }
+                }
+            }
+
+            Monitoring::update_price_stream_connected(false);
+            Monitoring::record_price_stream_reconnect();
+            reconnect_backoff(&config, attempt).await;
+            attempt = attempt.saturating_add(1);
+        }
+    }
+
+    pub async fn start_streaming<F>(&self, mut callback: F) -> Result<()>
     where
         F: FnMut(PriceUpdate) -> Result<bool>, // Return false to stop streaming
     {
-        let mut stream = self.client.stream_price_updates(self.token_addresses.clone()).await?;
-        
-        info!("Started streaming price updates for {} tokens", self.token_addresses.len());
-        
-        while let Some(update) = stream.message().await? {
-            debug!("Received price update for {}: ${:.6}", update.token_address, update.price_usd);
-            
-            // Call the callback function
+        let mut rx = self.subscribe();
+
+        info!("Started streaming price updates");
+
+        loop {
+            let update = match rx.recv().await {
+                Ok(update) => update,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("Price stream subscriber lagged, dropped {} messages", skipped);
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    info!("Price update stream closed");
+                    break;
+                }
+            };
+
             match callback(update) {
                 Ok(continue_streaming) => {
                     if !continue_streaming {
@@ -222,8 +481,7 @@ impl PriceUpdateStream {
                 }
             }
         }
-        
-        info!("Price update stream ended");
+
         Ok(())
     }
 }
@@ -1,5 +1,8 @@
 use anyhow::Result;
-use log::{debug, error, info, warn};
+use tracing::{debug, error, info, warn};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
@@ -15,11 +18,48 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::RwLock;
 
 use crate::config::Config;
+use crate::fee_oracle::FeeOracle;
+use crate::replayer::TransactionReplayer;
+use crate::tpu_client::TpuClient;
 
 pub struct JitoClient {
     rpc_client: RpcClient,
+    block_engine_client: Client,
     config: Arc<RwLock<Config>>,
-    tip_account: Pubkey,
+    tip_accounts: Vec<Pubkey>,
+    /// Validator identity presented on QUIC/TPU connections for staked packet
+    /// treatment. Falls back to an ephemeral unstaked identity when unset.
+    identity: Arc<Keypair>,
+    /// Shared EIP-1559-style base-fee tracker fed by this client's own
+    /// prioritization-fee polling, so the fee rises during sustained
+    /// contention and decays once blocks stop being full.
+    fee_oracle: Arc<FeeOracle>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct InflightBundleStatus {
+    bundle_id: String,
+    status: String, // "Pending" | "Landed" | "Failed" | "Invalid"
+    landed_slot: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BundleStatusEntry {
+    bundle_id: String,
+    confirmation_status: Option<String>,
+    slot: Option<u64>,
+    transactions: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone)]
@@ -37,30 +77,62 @@ pub struct Bundle {
 }
 
 impl JitoClient {
-    pub fn new(config: Arc<RwLock<Config>>) -> Result<Self> {
+    pub fn new(config: Arc<RwLock<Config>>, fee_oracle: Arc<FeeOracle>) -> Result<Self> {
         let config_guard = config.read().unwrap();
         let rpc_client = RpcClient::new_with_commitment(
             config_guard.solana_rpc_url.clone(),
             CommitmentConfig::confirmed(),
         );
         
-        let tip_account = config_guard.jito_tip_account.parse()?;
+        let tip_accounts: Vec<Pubkey> = if config_guard.jito_tip_accounts.is_empty() {
+            vec![config_guard.jito_tip_account.parse()?]
+        } else {
+            config_guard
+                .jito_tip_accounts
+                .iter()
+                .map(|a| a.parse())
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        let identity = crate::utils::load_identity_keypair(config_guard.identity_keypair_path.as_deref());
         drop(config_guard);
-        
+
+        let block_engine_client = Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()?;
+
         Ok(Self {
             rpc_client,
+            block_engine_client,
             config,
-            tip_account,
+            tip_accounts,
+            identity: Arc::new(identity),
+            fee_oracle,
         })
     }
+
+    /// Identity keypair QUIC/TPU connections present for staked priority.
+    pub fn identity(&self) -> Arc<Keypair> {
+        self.identity.clone()
+    }
+
+    fn pick_tip_account(&self) -> Pubkey {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let index = rng.gen_range(0..self.tip_accounts.len());
+        self.tip_accounts[index]
+    }
     
     pub async fn create_bundle(&self, transactions: Vec<Transaction>, keypair: &Keypair) -> Result<Bundle> {
         let mut bundle_transactions = Vec::new();
         let config_guard = self.config.read().await;
         
         for transaction in transactions {
-            // Calculate priority fee based on current network conditions
-            let priority_fee = self.calculate_priority_fee().await?;
+            // Calculate priority fee based on current network conditions,
+            // restricted to the writable accounts this swap actually touches
+            // so the estimate reflects contention on this pool, not the whole cluster.
+            let writable_accounts = writable_account_keys(&transaction);
+            let priority_fee = self.calculate_priority_fee_for_accounts(&writable_accounts).await?;
             
             // Create tip transaction
             let tip_amount = config_guard.jito_tip_amount;
@@ -95,86 +167,133 @@ impl JitoClient {
     
     pub async fn submit_bundle(&self, bundle: &Bundle) -> Result<String> {
         info!("Submitting bundle {} with {} transactions", bundle.bundle_id, bundle.transactions.len());
-        
-        // Convert bundle transactions to Solana transactions
-        let transactions: Vec<Transaction> = bundle.transactions
+
+        // Convert bundle transactions to base58-encoded wire transactions
+        let encoded_transactions: Vec<String> = bundle.transactions
             .iter()
-            .map(|bt| bt.transaction.clone())
+            .map(|bt| bs58::encode(bincode::serialize(&bt.transaction).unwrap()).into_string())
             .collect();
-        
-        // Submit bundle to Jito
-        let bundle_id = self.rpc_client
-            .send_bundle(&transactions)
+
+        let config_guard = self.config.read().await;
+        let url = format!("{}/api/v1/bundles", config_guard.jito_url);
+        drop(config_guard);
+
+        let request_body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "sendBundle",
+            "params": [encoded_transactions],
+        });
+
+        let response = self.block_engine_client
+            .post(&url)
+            .json(&request_body)
+            .send()
             .await?;
-        
+
+        let parsed: JsonRpcResponse<String> = response.json().await?;
+
+        if let Some(error) = parsed.error {
+            return Err(anyhow::anyhow!("Jito Block Engine rejected bundle: {}", error.message));
+        }
+
+        let bundle_id = parsed.result.ok_or_else(|| anyhow::anyhow!("Block Engine returned no bundle id"))?;
+
         info!("Bundle submitted successfully: {}", bundle_id);
         Ok(bundle_id)
     }
-    
+
     pub async fn wait_for_bundle_confirmation(&self, bundle_id: &str, timeout: Duration) -> Result<bool> {
         let start_time = SystemTime::now();
-        
+
         info!("Waiting for bundle confirmation: {}", bundle_id);
-        
+
         while start_time.elapsed()? < timeout {
-            // Check bundle status
             match self.get_bundle_status(bundle_id).await {
-                Ok(status) => {
-                    match status {
-                        BundleStatus::Confirmed => {
-                            info!("Bundle {} confirmed successfully", bundle_id);
-                            return Ok(true);
-                        }
-                        BundleStatus::Failed => {
-                            warn!("Bundle {} failed", bundle_id);
-                            return Ok(false);
-                        }
-                        BundleStatus::Pending => {
-                            debug!("Bundle {} still pending", bundle_id);
-                        }
+                Ok(BundleStatus::Confirmed) => {
+                    // Block Engine reports "Landed" as inflight; cross-check
+                    // the final getBundleStatuses call to resolve the slot.
+                    if let Ok(Some(slot)) = self.get_landed_slot(bundle_id).await {
+                        info!("Bundle {} confirmed in slot {}", bundle_id, slot);
+                        return Ok(true);
                     }
+                    debug!("Bundle {} reported landed but slot not yet indexed", bundle_id);
+                }
+                Ok(BundleStatus::Failed) => {
+                    warn!("Bundle {} failed", bundle_id);
+                    return Ok(false);
+                }
+                Ok(BundleStatus::Pending) => {
+                    debug!("Bundle {} still pending", bundle_id);
                 }
                 Err(e) => {
                     debug!("Error checking bundle status: {}", e);
                 }
             }
-            
-            // Wait before next check
+
             tokio::time::sleep(Duration::from_millis(100)).await;
         }
-        
+
         warn!("Bundle {} confirmation timeout", bundle_id);
         Ok(false)
     }
     
     async fn calculate_priority_fee(&self) -> Result<u64> {
-        // Get recent priority fee data
+        self.calculate_priority_fee_for_accounts(&[]).await
+    }
+
+    /// Estimate a priority fee from the distribution of recent prioritization
+    /// fees rather than their mean, since the distribution is heavily
+    /// right-skewed during a mint stampede. Restricting `writable_accounts`
+    /// to the pool being traded reflects contention on that pool specifically.
+    ///
+    /// The percentile fee and the window's median are fed into `fee_oracle`
+    /// as an EIP-1559-style (gas_used, gas_target) pair so sustained
+    /// contention on this pool raises the tracked base fee and quiet blocks
+    /// let it decay, rather than reacting to this single sample alone.
+    async fn calculate_priority_fee_for_accounts(&self, writable_accounts: &[Pubkey]) -> Result<u64> {
         let recent_fees = self.rpc_client
-            .get_recent_prioritization_fees(&[])
+            .get_recent_prioritization_fees(writable_accounts)
             .await?;
-        
+
         if recent_fees.is_empty() {
             return Ok(100000); // Default priority fee
         }
-        
-        // Calculate average priority fee with multiplier
+
         let config_guard = self.config.read().await;
-        let multiplier = config_guard.priority_fee_multiplier;
+        let percentile = config_guard.priority_fee_percentile;
+        let max_fee = config_guard.max_priority_fee;
         drop(config_guard);
-        
-        let avg_fee = recent_fees.iter().map(|f| f.prioritization_fee).sum::<u64>() / recent_fees.len() as u64;
-        let adjusted_fee = (avg_fee as f64 * multiplier) as u64;
-        
-        Ok(adjusted_fee.max(100000)) // Minimum 0.0001 SOL
+
+        let mut fees: Vec<u64> = recent_fees.iter().map(|f| f.prioritization_fee).collect();
+        fees.sort_unstable();
+
+        let percentile_fee = percentile_of(&fees, percentile);
+        let median_fee = percentile_of(&fees, 50.0);
+        self.fee_oracle.observe_slot(percentile_fee, median_fee.max(1)).await;
+
+        // Urgency of 1.0 treats this pool's contention as the full signal;
+        // `suggested_priority_fee` layers `priority_fee_multiplier` on top as
+        // the elasticity surcharge, replacing the old flat percentile-times-
+        // multiplier calculation with the oracle's smoothed base fee.
+        let suggested_fee = self.fee_oracle.suggested_priority_fee(1.0).await;
+
+        let floored = suggested_fee.max(100000); // Minimum 0.0001 SOL
+        Ok(match max_fee {
+            Some(cap) => floored.min(cap),
+            None => floored,
+        })
     }
     
     async fn create_tip_transaction(&self, keypair: &Keypair, tip_amount: u64) -> Result<Transaction> {
         let recent_blockhash = self.rpc_client.get_latest_blockhash().await?;
-        
-        // Create tip instruction
+
+        // Rotate across the published tip accounts so we don't hammer a
+        // single write-lock hotspot every bundle.
+        let tip_account = self.pick_tip_account();
         let tip_instruction = solana_sdk::system_instruction::transfer(
             &keypair.pubkey(),
-            &self.tip_account,
+            &tip_account,
             tip_amount,
         );
         
@@ -184,7 +303,14 @@ impl JitoClient {
         Ok(transaction)
     }
     
-    fn generate_bundle_id(&self) -> String {
+    pub(crate) async fn get_last_valid_blockhash_height(&self) -> Result<u64> {
+        let (_, last_valid_height) = self.rpc_client
+            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+            .await?;
+        Ok(last_valid_height)
+    }
+
+    pub(crate) fn generate_bundle_id(&self) -> String {
         use rand::Rng;
         let mut rng = rand::thread_rng();
         let random_bytes: [u8; 16] = rng.gen();
@@ -192,20 +318,71 @@ impl JitoClient {
     }
     
     async fn get_bundle_status(&self, bundle_id: &str) -> Result<BundleStatus> {
-        // This would typically involve checking Jito's bundle status endpoint
-        // For now, we'll simulate the status check
-        tokio::time::sleep(Duration::from_millis(50)).await;
-        
-        // Simulate random status for demo purposes
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
-        let status = match rng.gen_range(0..3) {
-            0 => BundleStatus::Pending,
-            1 => BundleStatus::Confirmed,
-            _ => BundleStatus::Failed,
-        };
-        
-        Ok(status)
+        let config_guard = self.config.read().await;
+        let url = format!("{}/api/v1/bundles", config_guard.jito_url);
+        drop(config_guard);
+
+        let request_body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getInflightBundleStatuses",
+            "params": [[bundle_id]],
+        });
+
+        let response = self.block_engine_client
+            .post(&url)
+            .json(&request_body)
+            .send()
+            .await?;
+
+        let parsed: JsonRpcResponse<Vec<InflightBundleStatus>> = response.json().await?;
+
+        if let Some(error) = parsed.error {
+            return Err(anyhow::anyhow!("Jito Block Engine error: {}", error.message));
+        }
+
+        let statuses = parsed.result.unwrap_or_default();
+        let entry = statuses
+            .into_iter()
+            .find(|s| s.bundle_id == bundle_id)
+            .ok_or_else(|| anyhow::anyhow!("Bundle {} not found in inflight statuses", bundle_id))?;
+
+        Ok(match entry.status.as_str() {
+            "Landed" => BundleStatus::Confirmed,
+            "Failed" | "Invalid" => BundleStatus::Failed,
+            _ => BundleStatus::Pending,
+        })
+    }
+
+    async fn get_landed_slot(&self, bundle_id: &str) -> Result<Option<u64>> {
+        let config_guard = self.config.read().await;
+        let url = format!("{}/api/v1/bundles", config_guard.jito_url);
+        drop(config_guard);
+
+        let request_body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getBundleStatuses",
+            "params": [[bundle_id]],
+        });
+
+        let response = self.block_engine_client
+            .post(&url)
+            .json(&request_body)
+            .send()
+            .await?;
+
+        let parsed: JsonRpcResponse<Vec<BundleStatusEntry>> = response.json().await?;
+
+        if let Some(error) = parsed.error {
+            return Err(anyhow::anyhow!("Jito Block Engine error: {}", error.message));
+        }
+
+        let statuses = parsed.result.unwrap_or_default();
+        Ok(statuses
+            .into_iter()
+            .find(|s| s.bundle_id == bundle_id)
+            .and_then(|s| s.slot))
     }
 }
 
@@ -216,50 +393,173 @@ pub enum BundleStatus {
     Failed,
 }
 
+/// Observability counters for submitted bundles, analogous to lite-rpc's
+/// SentTransactionInfo/TPS tracking so operators can tune
+/// `priority_fee_multiplier` and `jito_tip_amount` against observed landing rates.
+#[derive(Debug, Default)]
+struct BundleMetricsInner {
+    submitted: std::sync::atomic::AtomicU64,
+    landed: std::sync::atomic::AtomicU64,
+    failed: std::sync::atomic::AtomicU64,
+    timed_out: std::sync::atomic::AtomicU64,
+    total_confirmation_latency_ms: std::sync::atomic::AtomicU64,
+    total_tip_lamports_spent: std::sync::atomic::AtomicU64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BundleMetricsSnapshot {
+    pub submitted: u64,
+    pub landed: u64,
+    pub failed: u64,
+    pub timed_out: u64,
+    pub average_confirmation_latency_ms: f64,
+    pub total_tip_lamports_spent: u64,
+}
+
 pub struct BundleManager {
     jito_client: JitoClient,
+    tpu_client: TpuClient,
+    replayer: Arc<TransactionReplayer>,
     pending_bundles: std::collections::HashMap<String, Bundle>,
     config: Arc<RwLock<Config>>,
+    metrics: Arc<BundleMetricsInner>,
 }
 
 impl BundleManager {
-    pub fn new(config: Arc<RwLock<Config>>) -> Result<Self> {
-        let jito_client = JitoClient::new(config.clone())?;
-        
+    pub fn new(config: Arc<RwLock<Config>>, fee_oracle: Arc<FeeOracle>) -> Result<Self> {
+        let jito_client = JitoClient::new(config.clone(), fee_oracle)?;
+        let tpu_client = TpuClient::new(config.clone())?;
+        tpu_client.start_background_tasks();
+
+        let replayer = Arc::new(TransactionReplayer::new(config.clone(), tpu_client.clone())?);
+        replayer.start();
+
+        let metrics = Arc::new(BundleMetricsInner::default());
+        spawn_metrics_reporter(metrics.clone());
+
         Ok(Self {
             jito_client,
+            tpu_client,
+            replayer,
             pending_bundles: std::collections::HashMap::new(),
             config,
+            metrics,
         })
     }
-    
+
+    /// Snapshot of submission/landing/latency/tip-spend counters, for tuning
+    /// `priority_fee_multiplier` and `jito_tip_amount` against observed reality.
+    pub fn metrics(&self) -> BundleMetricsSnapshot {
+        use std::sync::atomic::Ordering;
+
+        let landed = self.metrics.landed.load(Ordering::Relaxed);
+        let total_latency = self.metrics.total_confirmation_latency_ms.load(Ordering::Relaxed);
+
+        BundleMetricsSnapshot {
+            submitted: self.metrics.submitted.load(Ordering::Relaxed),
+            landed,
+            failed: self.metrics.failed.load(Ordering::Relaxed),
+            timed_out: self.metrics.timed_out.load(Ordering::Relaxed),
+            average_confirmation_latency_ms: if landed > 0 {
+                total_latency as f64 / landed as f64
+            } else {
+                0.0
+            },
+            total_tip_lamports_spent: self.metrics.total_tip_lamports_spent.load(Ordering::Relaxed),
+        }
+    }
+
     pub async fn submit_transaction_bundle(&mut self, transactions: Vec<Transaction>, keypair: &Keypair) -> Result<String> {
+        let config_guard = self.config.read().await;
+        let enable_direct_tpu = config_guard.enable_direct_tpu;
+        let tpu_fanout = config_guard.tpu_fanout;
+        drop(config_guard);
+
+        let last_valid_blockhash_height = self.jito_client.get_last_valid_blockhash_height().await?;
+
+        if enable_direct_tpu {
+            // Skip the bundle/tip machinery entirely and fan the raw
+            // transactions out to the upcoming leaders over QUIC.
+            let mut signatures = Vec::new();
+            for transaction in &transactions {
+                self.tpu_client.send_transaction(transaction, tpu_fanout).await?;
+                signatures.push(self.replayer.register(transaction.clone(), last_valid_blockhash_height)?);
+            }
+
+            let submission_id = self.jito_client.generate_bundle_id();
+            info!("Submitted {} transactions directly to leaders via TPU: {}", transactions.len(), submission_id);
+            return Ok(submission_id);
+        }
+
+        // Register every transaction with the replayer before submission so
+        // a dropped leader packet gets rebroadcast instead of silently lost.
+        for transaction in &transactions {
+            self.replayer.register(transaction.clone(), last_valid_blockhash_height)?;
+        }
+
         // Create bundle
         let bundle = self.jito_client.create_bundle(transactions, keypair).await?;
         let bundle_id = bundle.bundle_id.clone();
-        
+
         // Store bundle
         self.pending_bundles.insert(bundle_id.clone(), bundle);
-        
+
         // Submit bundle
         let submitted_id = self.jito_client.submit_bundle(&self.pending_bundles[&bundle_id]).await?;
-        
+        self.metrics.submitted.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+        let tip_amount_spent: u64 = self.pending_bundles[&bundle_id]
+            .transactions
+            .iter()
+            .map(|bt| bt.tip_amount)
+            .sum();
+
         // Start confirmation monitoring
         let config_guard = self.config.read().await;
         let timeout = Duration::from_millis(config_guard.bundle_timeout);
         drop(config_guard);
-        
+
         let jito_client = self.jito_client.clone();
+        let replayer = self.replayer.clone();
+        let metrics = self.metrics.clone();
+        let bundle_transactions: Vec<Transaction> = self.pending_bundles[&bundle_id]
+            .transactions
+            .iter()
+            .map(|bt| bt.transaction.clone())
+            .collect();
         let bundle_id_clone = bundle_id.clone();
+        let submitted_at = SystemTime::now();
         tokio::spawn(async move {
+            use std::sync::atomic::Ordering;
+
             let confirmed = jito_client.wait_for_bundle_confirmation(&submitted_id, timeout).await;
             match confirmed {
-                Ok(true) => info!("Bundle {} confirmed", submitted_id),
-                Ok(false) => warn!("Bundle {} failed or timed out", submitted_id),
-                Err(e) => error!("Error waiting for bundle {}: {}", submitted_id, e),
+                Ok(true) => {
+                    info!("Bundle {} confirmed", submitted_id);
+                    metrics.landed.fetch_add(1, Ordering::Relaxed);
+                    metrics.total_tip_lamports_spent.fetch_add(tip_amount_spent, Ordering::Relaxed);
+                    if let Ok(elapsed) = submitted_at.elapsed() {
+                        metrics.total_confirmation_latency_ms.fetch_add(elapsed.as_millis() as u64, Ordering::Relaxed);
+                    }
+                }
+                Ok(false) => {
+                    warn!("Bundle {} failed or timed out", submitted_id);
+                    metrics.timed_out.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(e) => {
+                    error!("Error waiting for bundle {}: {}", submitted_id, e);
+                    metrics.failed.fetch_add(1, Ordering::Relaxed);
+                }
             }
+
+            for transaction in &bundle_transactions {
+                if let Some(signature) = transaction.signatures.first() {
+                    replayer.deregister(signature);
+                }
+            }
+            let _ = bundle_id_clone;
         });
-        
+
         Ok(submitted_id)
     }
     
@@ -286,13 +586,72 @@ impl BundleManager {
     }
 }
 
+/// Periodically logs landing rate and latency so operators can tune fee/tip
+/// config against observed reality without polling `BundleManager::metrics`.
+fn spawn_metrics_reporter(metrics: Arc<BundleMetricsInner>) {
+    tokio::spawn(async move {
+        use std::sync::atomic::Ordering;
+        let mut interval = tokio::time::interval(Duration::from_secs(30));
+
+        loop {
+            interval.tick().await;
+
+            let submitted = metrics.submitted.load(Ordering::Relaxed);
+            let landed = metrics.landed.load(Ordering::Relaxed);
+            let failed = metrics.failed.load(Ordering::Relaxed);
+            let timed_out = metrics.timed_out.load(Ordering::Relaxed);
+
+            if submitted == 0 {
+                continue;
+            }
+
+            let land_rate = landed as f64 / submitted as f64 * 100.0;
+            let avg_latency = if landed > 0 {
+                metrics.total_confirmation_latency_ms.load(Ordering::Relaxed) as f64 / landed as f64
+            } else {
+                0.0
+            };
+
+            info!(
+                "Bundle metrics: submitted={}, landed={} ({:.1}%), failed={}, timed_out={}, avg_confirmation={:.0}ms, tip_spent={} lamports",
+                submitted, landed, land_rate, failed, timed_out, avg_latency,
+                metrics.total_tip_lamports_spent.load(Ordering::Relaxed)
+            );
+        }
+    });
+}
+
+/// Nearest-rank percentile over an already-sorted slice, e.g. `p=75.0` for p75.
+fn percentile_of(sorted_values: &[u64], p: f64) -> u64 {
+    if sorted_values.is_empty() {
+        return 0;
+    }
+
+    let rank = ((p / 100.0) * (sorted_values.len() - 1) as f64).round() as usize;
+    sorted_values[rank.min(sorted_values.len() - 1)]
+}
+
+fn writable_account_keys(transaction: &Transaction) -> Vec<Pubkey> {
+    let message = &transaction.message;
+    message
+        .account_keys
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| message.is_maybe_writable(*index))
+        .map(|(_, key)| *key)
+        .collect()
+}
+
 // Clone implementation for JitoClient
 impl Clone for JitoClient {
     fn clone(&self) -> Self {
         Self {
             rpc_client: self.rpc_client.clone(),
+            block_engine_client: self.block_engine_client.clone(),
             config: self.config.clone(),
-            tip_account: self.tip_account,
+            tip_accounts: self.tip_accounts.clone(),
+            identity: self.identity.clone(),
+            fee_oracle: self.fee_oracle.clone(),
         }
     }
 }
@@ -0,0 +1,162 @@
+use anyhow::Result;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{instruction::AccountMeta, instruction::Instruction, pubkey::Pubkey};
+use std::str::FromStr;
+
+/// Native SOL mint address, used as the input mint for every snipe buy.
+pub const NATIVE_SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// Thin client over Jupiter's v6 quote/swap-instructions API. Jupiter already
+/// routes across every AMM it indexes (including PumpSwap pools), so this
+/// replaces hand-rolled swap construction instead of talking to the PumpSwap
+/// program directly.
+pub struct JupiterClient {
+    http: Client,
+    base_url: String,
+}
+
+/// Jupiter's quote response is large and version-sensitive; we pass it
+/// through opaquely to `/swap-instructions` rather than modeling every field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteResponse(serde_json::Value);
+
+impl QuoteResponse {
+    /// The minimum output amount Jupiter itself will accept for the quoted
+    /// slippage; used as the floor a pre-submit simulation must clear.
+    pub fn min_out_amount(&self) -> Result<u64> {
+        self.0
+            .get("otherAmountThreshold")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("quote response missing otherAmountThreshold"))?
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("invalid otherAmountThreshold in quote response: {}", e))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SwapInstructionsRequest {
+    #[serde(rename = "userPublicKey")]
+    user_public_key: String,
+    #[serde(rename = "quoteResponse")]
+    quote_response: serde_json::Value,
+    #[serde(rename = "wrapAndUnwrapSol")]
+    wrap_and_unwrap_sol: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct SwapInstructionsResponse {
+    #[serde(rename = "setupInstructions", default)]
+    setup_instructions: Vec<RawInstruction>,
+    #[serde(rename = "swapInstruction")]
+    swap_instruction: RawInstruction,
+    #[serde(rename = "cleanupInstruction")]
+    cleanup_instruction: Option<RawInstruction>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawInstruction {
+    #[serde(rename = "programId")]
+    program_id: String,
+    accounts: Vec<RawAccountMeta>,
+    data: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawAccountMeta {
+    pubkey: String,
+    #[serde(rename = "isSigner")]
+    is_signer: bool,
+    #[serde(rename = "isWritable")]
+    is_writable: bool,
+}
+
+impl RawInstruction {
+    fn into_instruction(self) -> Result<Instruction> {
+        let program_id = Pubkey::from_str(&self.program_id)?;
+        let accounts = self
+            .accounts
+            .into_iter()
+            .map(|meta| {
+                Ok(AccountMeta {
+                    pubkey: Pubkey::from_str(&meta.pubkey)?,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let data = base64::decode(&self.data)?;
+
+        Ok(Instruction { program_id, accounts, data })
+    }
+}
+
+impl JupiterClient {
+    pub fn new(base_url: String) -> Self {
+        Self {
+            http: Client::new(),
+            base_url,
+        }
+    }
+
+    /// Fetch a quote swapping `amount` lamports of `input_mint` into `output_mint`.
+    pub async fn get_quote(
+        &self,
+        input_mint: &Pubkey,
+        output_mint: &Pubkey,
+        amount: u64,
+        slippage_bps: u16,
+    ) -> Result<QuoteResponse> {
+        let url = format!("{}/quote", self.base_url);
+        let response = self
+            .http
+            .get(&url)
+            .query(&[
+                ("inputMint", input_mint.to_string()),
+                ("outputMint", output_mint.to_string()),
+                ("amount", amount.to_string()),
+                ("slippageBps", slippage_bps.to_string()),
+            ])
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let quote: serde_json::Value = response.json().await?;
+        Ok(QuoteResponse(quote))
+    }
+
+    /// Turn a quote into the concrete instructions needed to execute the swap.
+    pub async fn get_swap_instructions(
+        &self,
+        quote: &QuoteResponse,
+        user_public_key: &Pubkey,
+    ) -> Result<Vec<Instruction>> {
+        let url = format!("{}/swap-instructions", self.base_url);
+        let request = SwapInstructionsRequest {
+            user_public_key: user_public_key.to_string(),
+            quote_response: quote.0.clone(),
+            wrap_and_unwrap_sol: true,
+        };
+
+        let response: SwapInstructionsResponse = self
+            .http
+            .post(&url)
+            .json(&request)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let mut instructions = Vec::new();
+        for setup in response.setup_instructions {
+            instructions.push(setup.into_instruction()?);
+        }
+        instructions.push(response.swap_instruction.into_instruction()?);
+        if let Some(cleanup) = response.cleanup_instruction {
+            instructions.push(cleanup.into_instruction()?);
+        }
+
+        Ok(instructions)
+    }
+}
@@ -0,0 +1,178 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tracing::info;
+
+/// Exponential bucket boundaries in milliseconds: powers of two from 1ms up
+/// through ~32s, plus a catch-all overflow bucket for anything slower. Fixed
+/// buckets keep memory constant regardless of sample count, at the cost of
+/// the coarser resolution `hdrhistogram::Histogram` (used by
+/// [`crate::latency_metrics::LatencyMetrics`]) gives up nothing for — this
+/// is meant to be a cheap always-on counter, not a replacement for it.
+fn default_boundaries_ms() -> Vec<u64> {
+    let mut boundaries = Vec::new();
+    let mut bound = 1u64;
+    while bound <= 32_768 {
+        boundaries.push(bound);
+        bound *= 2;
+    }
+    boundaries.push(u64::MAX);
+    boundaries
+}
+
+/// A fixed-bucket latency histogram: each observation increments one atomic
+/// counter, and percentile queries walk cumulative bucket counts to find the
+/// bucket containing the target rank.
+pub struct LatencyHistogram {
+    boundaries: Vec<u64>,
+    buckets: Vec<AtomicU64>,
+}
+
+impl LatencyHistogram {
+    pub fn new() -> Self {
+        Self::with_boundaries(default_boundaries_ms())
+    }
+
+    pub fn with_boundaries(mut boundaries: Vec<u64>) -> Self {
+        boundaries.sort_unstable();
+        boundaries.dedup();
+        let buckets = boundaries.iter().map(|_| AtomicU64::new(0)).collect();
+        Self { boundaries, buckets }
+    }
+
+    /// Record a single observation, in milliseconds.
+    pub fn record(&self, value_ms: u64) {
+        let idx = self
+            .boundaries
+            .partition_point(|b| *b <= value_ms)
+            .min(self.buckets.len() - 1);
+        self.buckets[idx].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().map(|b| b.load(Ordering::Relaxed)).sum()
+    }
+
+    /// Smallest bucket boundary whose cumulative count reaches `percentile`
+    /// (0.0-1.0) of all observations recorded so far. Returns 0 if nothing
+    /// has been recorded yet.
+    pub fn percentile(&self, percentile: f64) -> u64 {
+        let total = self.count();
+        if total == 0 {
+            return 0;
+        }
+
+        let target_rank = ((total as f64) * percentile).ceil() as u64;
+        let mut cumulative = 0u64;
+        for (bucket, boundary) in self.buckets.iter().zip(self.boundaries.iter()) {
+            cumulative += bucket.load(Ordering::Relaxed);
+            if cumulative >= target_rank {
+                return *boundary;
+            }
+        }
+
+        *self.boundaries.last().unwrap()
+    }
+
+    pub fn snapshot(&self) -> LatencyHistogramSnapshot {
+        LatencyHistogramSnapshot {
+            p50: self.percentile(0.50),
+            p90: self.percentile(0.90),
+            p99: self.percentile(0.99),
+            samples: self.count(),
+        }
+    }
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// p50/p90/p99 read out of a [`LatencyHistogram`], in milliseconds.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LatencyHistogramSnapshot {
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub samples: u64,
+}
+
+impl std::fmt::Display for LatencyHistogramSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "p50={}ms p90={}ms p99={}ms samples={}", self.p50, self.p90, self.p99, self.samples)
+    }
+}
+
+/// Submission/confirmation latency tracking for [`crate::nozomi_client::NozomiManager`]:
+/// the relay-reported `confirmation_time_ms` from `NozomiResponse`/`NozomiStatus`,
+/// the locally-measured submission-to-confirmed-or-failed latency, and rolling
+/// success/failure counts. Kept separate from `LatencyMetrics` since that one
+/// tracks end-to-end build-to-submit latency across every backend, while this
+/// tracks relay/stream confirmation specifically.
+pub struct ConfirmationMetrics {
+    pub relay_confirmation_time: LatencyHistogram,
+    pub submission_to_resolved: LatencyHistogram,
+    successes: AtomicU64,
+    failures: AtomicU64,
+}
+
+impl ConfirmationMetrics {
+    pub fn new() -> Self {
+        Self {
+            relay_confirmation_time: LatencyHistogram::new(),
+            submission_to_resolved: LatencyHistogram::new(),
+            successes: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+        }
+    }
+
+    /// Record the relay's own `confirmation_time_ms`, when it reports one
+    /// (only the Nozomi polling path has this; the Geyser stream path does
+    /// not get a relay-measured figure).
+    pub fn record_relay_confirmation_time_ms(&self, confirmation_time_ms: u64) {
+        self.relay_confirmation_time.record(confirmation_time_ms);
+    }
+
+    /// Record the outcome of a submission and how long it took from
+    /// submission to resolving (confirmed or failed/timed out), regardless
+    /// of which path (stream or polling) resolved it.
+    pub fn record_outcome(&self, confirmed: bool, elapsed: Duration) {
+        self.submission_to_resolved.record(elapsed.as_millis().min(u64::MAX as u128) as u64);
+        if confirmed {
+            self.successes.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.failures.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn success_count(&self) -> u64 {
+        self.successes.load(Ordering::Relaxed)
+    }
+
+    pub fn failure_count(&self) -> u64 {
+        self.failures.load(Ordering::Relaxed)
+    }
+
+    /// Human-readable one-shot report, for periodic printing (e.g. from the
+    /// monitoring example) without needing a tracing subscriber attached.
+    pub fn report(&self) -> String {
+        format!(
+            "confirmations: {} ok, {} failed | submission->resolved: {} | relay-reported confirmation_time: {}",
+            self.success_count(),
+            self.failure_count(),
+            self.submission_to_resolved.snapshot(),
+            self.relay_confirmation_time.snapshot(),
+        )
+    }
+
+    pub fn log_report(&self) {
+        info!("{}", self.report());
+    }
+}
+
+impl Default for ConfirmationMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
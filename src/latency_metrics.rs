@@ -0,0 +1,87 @@
+use hdrhistogram::Histogram;
+use tracing::info;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Tracks end-to-end submission latency (from transaction build to
+/// submission return) in an `hdrhistogram::Histogram<u64>` per
+/// confirmation-service/event-type pair, so tail latency can be compared
+/// across "jito"/"nozomi"/"tpu" and across snipes vs each MEV strategy.
+///
+/// Values are recorded in microseconds with 3 significant digits of
+/// precision over a 1µs-60s range, which keeps memory fixed regardless of
+/// how many samples are recorded.
+pub struct LatencyMetrics {
+    histograms: dashmap::DashMap<String, Mutex<Histogram<u64>>>,
+    submissions_since_report: AtomicU64,
+}
+
+impl LatencyMetrics {
+    pub fn new() -> Self {
+        Self {
+            histograms: dashmap::DashMap::new(),
+            submissions_since_report: AtomicU64::new(0),
+        }
+    }
+
+    fn key(service: &str, event: &str) -> String {
+        format!("{}:{}", service, event)
+    }
+
+    /// Record the elapsed time since `start` for the given service/event pair.
+    pub fn record(&self, service: &str, event: &str, start: Instant) {
+        let elapsed_micros = start.elapsed().as_micros().min(u64::MAX as u128) as u64;
+        let key = Self::key(service, event);
+
+        let entry = self
+            .histograms
+            .entry(key)
+            .or_insert_with(|| Mutex::new(Histogram::new_with_bounds(1, 60_000_000, 3).unwrap()));
+
+        if let Ok(mut histogram) = entry.try_lock() {
+            let _ = histogram.record(elapsed_micros);
+        }
+
+        self.submissions_since_report.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Spawn a background task that periodically logs p50/p90/p99/max per
+    /// backend/event pair plus a rolling transactions-per-second count.
+    pub fn start_reporter(self: Arc<Self>, interval: Duration) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+
+                let submitted = self.submissions_since_report.swap(0, Ordering::Relaxed);
+                let tps = submitted as f64 / interval.as_secs_f64();
+                info!("Submission throughput: {:.2} tx/s over the last {:?}", tps, interval);
+
+                for entry in self.histograms.iter() {
+                    let histogram = entry.value().lock().await;
+                    if histogram.len() == 0 {
+                        continue;
+                    }
+
+                    info!(
+                        "Latency[{}]: p50={}us p90={}us p99={}us max={}us samples={}",
+                        entry.key(),
+                        histogram.value_at_quantile(0.50),
+                        histogram.value_at_quantile(0.90),
+                        histogram.value_at_quantile(0.99),
+                        histogram.max(),
+                        histogram.len(),
+                    );
+                }
+            }
+        });
+    }
+}
+
+impl Default for LatencyMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,29 +1,78 @@
 pub mod config;
+pub mod money;
+pub mod backtest;
 pub mod grpc_client;
 pub mod jito_client;
 pub mod nozomi_client;
+pub mod confirmation_stream;
+pub mod submission_store;
+pub mod tpu_client;
+pub mod replayer;
+pub mod latency_metrics;
+pub mod latency_histogram;
+pub mod throughput_tracker;
+pub mod fee_oracle;
+pub mod pool_model;
+pub mod amm;
+pub mod margin;
+pub mod priority_fee_oracle;
+pub mod pool_state_retriever;
+pub mod jupiter_client;
 pub mod mev_detector;
+pub mod work_queue;
+pub mod simulation_guard;
+pub mod state_guard;
+pub mod oracle_aggregator;
+pub mod rpc_server;
+pub mod rebalancer;
 pub mod sniper;
+pub mod trade_store;
 pub mod monitoring;
+pub mod error_tracking;
 pub mod utils;
 
 // Re-export main types for easier access
 pub use config::{Config, MEVStrategy};
+pub use money::{FixedU256, Lamports};
 pub use grpc_client::{PumpSwapGrpcClient, TokenListingStream, PriceUpdateStream};
 pub use jito_client::{JitoClient, BundleManager, Bundle, BundleTransaction};
-pub use nozomi_client::{NozomiClient, NozomiManager, NozomiSubmission};
+pub use nozomi_client::{NozomiClient, NozomiManager, NozomiSubmission, SubmissionRoute};
+pub use confirmation_stream::ConfirmationSubscriber;
+pub use submission_store::{NoopSubmissionStore, SubmissionRecord, SubmissionStatus, SubmissionStore};
+pub use tpu_client::TpuClient;
+pub use replayer::{TransactionReplayer, SentTransactionInfo};
+pub use latency_metrics::LatencyMetrics;
+pub use latency_histogram::{ConfirmationMetrics, LatencyHistogram, LatencyHistogramSnapshot};
+pub use throughput_tracker::ThroughputTracker;
+pub use fee_oracle::FeeOracle;
+pub use pool_model::PoolModel;
+pub use amm::ConstantProductPool;
+pub use margin::{bankruptcy_price, liquidation_price};
+pub use priority_fee_oracle::PriorityFeeOracle;
+pub use pool_state_retriever::{FixedOrderRetriever, PoolStateRetriever, ScanningRetriever};
+pub use jupiter_client::JupiterClient;
+pub use trade_store::TradeStore;
 pub use mev_detector::{MEVDetector, MEVOpportunity, MEVSignal, MEVPriority};
+pub use work_queue::{TradeJob, TradeQueue};
+pub use simulation_guard::{FillAssertion, SimulationGuard};
+pub use state_guard::{PoolStateSnapshot, StateGuard};
+pub use oracle_aggregator::{ConsensusPrice, OracleAggregator};
+pub use rpc_server::{ControlServer, ConfigUpdate, TradeSummary};
 pub use sniper::SniperBot;
 pub use monitoring::{Monitoring, TradeLogger};
+pub use backtest::{run_backtest, BacktestReport};
+pub use error_tracking::ErrorTracker;
 pub use utils::*;
 
 // Version information
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const NAME: &str = env!("CARGO_PKG_NAME");
 
-/// Initialize the sniper bot library
+/// Initialize the sniper bot library with a default `tracing` subscriber.
+/// Binaries that want the JSON/pretty split `main.rs` sets up for the CLI
+/// should configure `tracing_subscriber` themselves instead of calling this.
 pub fn init() {
-    env_logger::init();
+    tracing_subscriber::fmt::init();
 }
 
 /// Get library information
@@ -1,15 +1,42 @@
 use clap::Parser;
-use log::{info, error};
+use tracing::{info, error};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 mod config;
+mod money;
+mod backtest;
 mod grpc_client;
 mod jito_client;
 mod nozomi_client;
+mod confirmation_stream;
+mod submission_store;
+mod tpu_client;
+mod replayer;
+mod latency_metrics;
+mod latency_histogram;
+mod throughput_tracker;
+mod fee_oracle;
+mod pool_model;
+mod amm;
+mod margin;
+mod priority_fee_oracle;
+mod pool_state_retriever;
+mod jupiter_client;
 mod mev_detector;
+mod work_queue;
+mod simulation_guard;
+mod state_guard;
+mod oracle_aggregator;
+mod rpc_server;
+mod rebalancer;
 mod sniper;
+mod trade_store;
 mod monitoring;
+mod error_tracking;
 mod utils;
 
 use config::Config;
@@ -53,17 +80,36 @@ struct Args {
     /// Snipe amount in SOL
     #[arg(long, default_value = "1.0")]
     snipe_amount: f64,
+
+    /// Replay a recorded listing/price-update stream (JSON or CSV) through
+    /// the detection/decision path in dry-run mode instead of trading live.
+    #[arg(long)]
+    backtest: Option<String>,
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
     
-    // Initialize logging
+    // Initialize tracing. `--debug` is for a human staring at a terminal, so
+    // it gets the pretty layer; its absence means a process supervisor is
+    // almost certainly scraping stdout, so default to the JSON layer so logs
+    // stay machine-parseable (and line up with the structured trade spans
+    // `TradeLogger` emits) without the operator having to opt in.
     let log_level = if args.debug { "debug" } else { "info" };
-    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(log_level))
-        .init();
-    
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(log_level));
+    if args.debug {
+        tracing_subscriber::registry()
+            .with(env_filter)
+            .with(fmt::layer().pretty())
+            .init();
+    } else {
+        tracing_subscriber::registry()
+            .with(env_filter)
+            .with(fmt::layer().json())
+            .init();
+    }
+
     info!("Starting PumpSwap 0-Slot Sniper Bot");
     
     // Load configuration
@@ -75,10 +121,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     if !args.tokens.is_empty() {
         config.target_tokens = args.tokens;
     }
-    config.min_liquidity = args.min_liquidity;
+    config.min_liquidity = money::Lamports::from_sol(args.min_liquidity);
     config.max_slippage = args.max_slippage;
     config.max_gas_price = args.max_gas_price;
-    config.snipe_amount = args.snipe_amount;
+    config.snipe_amount = money::Lamports::from_sol(args.snipe_amount);
     
     // Set confirmation service
     if args.use_jito {
@@ -89,7 +135,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     // Create shared configuration
     let config = Arc::new(RwLock::new(config));
-    
+
+    // `--backtest` replays recorded history through the same detection
+    // logic instead of starting the live gRPC/RPC/monitoring stack, so a
+    // config can be tuned without risking funds.
+    if let Some(backtest_file) = &args.backtest {
+        info!("Running backtest against {}", backtest_file);
+        let report = backtest::run_backtest(backtest_file, config.clone()).await?;
+        println!("{}", report);
+        return Ok(());
+    }
+
     // Initialize monitoring
     let monitoring = monitoring::Monitoring::new(config.clone()).await?;
     
@@ -105,10 +161,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     info!("Starting sniper bot with configuration:");
     info!("  Target tokens: {:?}", config.read().await.target_tokens);
-    info!("  Min liquidity: {} SOL", config.read().await.min_liquidity);
+    info!("  Min liquidity: {}", config.read().await.min_liquidity);
     info!("  Max slippage: {}%", config.read().await.max_slippage);
     info!("  Confirmation service: {}", config.read().await.confirmation_service);
-    info!("  Snipe amount: {} SOL", config.read().await.snipe_amount);
+    info!("  Snipe amount: {}", config.read().await.snipe_amount);
     
     // Start the sniper bot
     match sniper.start().await {
@@ -0,0 +1,25 @@
+/// Maintenance-margin liquidation math for a position a sandwich/backrun
+/// leaves open, used by [`crate::mev_detector::MEVDetector::calculate_priority`]
+/// to penalize opportunities whose exit leaves a position close to
+/// liquidation instead of relying on a flat `risk_score` constant.
+
+/// Price at which `collateral` no longer covers `maintenance_margin` of a
+/// long position's notional at the current price, for a position of
+/// `position_size` units opened at `entry_price`. Solves
+/// `collateral + (price - entry_price) * position_size == maintenance_margin * position_size * price`
+/// for `price`. `collateral` and `entry_price * position_size` must be in
+/// the same unit (e.g. both lamports).
+pub fn liquidation_price(entry_price: f64, position_size: f64, collateral: f64, maintenance_margin: f64) -> f64 {
+    if position_size <= 0.0 || maintenance_margin >= 1.0 {
+        return 0.0;
+    }
+
+    ((entry_price * position_size) - collateral) / (position_size * (1.0 - maintenance_margin))
+}
+
+/// `liquidation_price` at a maintenance margin of 0% — the price at which
+/// collateral is fully exhausted and the position itself is underwater,
+/// rather than merely short of the exchange's maintenance requirement.
+pub fn bankruptcy_price(entry_price: f64, position_size: f64, collateral: f64) -> f64 {
+    liquidation_price(entry_price, position_size, collateral, 0.0)
+}
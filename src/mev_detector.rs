@@ -1,20 +1,42 @@
 use anyhow::Result;
-use log::{debug, error, info, warn};
+use tracing::{debug, error, info, warn};
 use serde::{Deserialize, Serialize};
 use solana_sdk::{
-    instruction::Instruction,
+    instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     signature::Keypair,
     transaction::Transaction,
 };
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::RwLock;
 
+use crate::amm::ConstantProductPool;
 use crate::config::{Config, MEVStrategy};
+use crate::margin::liquidation_price;
+use crate::money::LAMPORTS_PER_SOL;
+use crate::pool_state_retriever::PoolStateRetriever;
+use crate::priority_fee_oracle::PriorityFeeOracle;
 use crate::proto::pumpswap::*;
 
+/// Swap fee PumpSwap pools charge, in basis points, used to seed every
+/// [`ConstantProductPool`] this detector tracks.
+const POOL_FEE_BPS: u16 = 30;
+
+/// Solana's base per-signature network fee, charged on both the front-run
+/// and back-run leg of a sandwich.
+const BASE_TX_FEE_LAMPORTS: u64 = 5_000;
+/// Upper bound for the sandwich front-run search, expressed as a multiple of
+/// the victim's own trade size — wide enough to cover the slippage-feasible
+/// range for any pool this detector tracks without scanning unboundedly.
+const SANDWICH_SEARCH_MULTIPLIER: u128 = 20;
+/// Iteration count for both the slippage-feasibility binary search and the
+/// profit-maximizing ternary search below; halves (resp. thirds) the search
+/// range enough times to converge well past lamport precision.
+const SANDWICH_SEARCH_ITERATIONS: usize = 60;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MEVOpportunity {
     pub id: String,
@@ -28,6 +50,41 @@ pub struct MEVOpportunity {
     pub required_transactions: Vec<Transaction>,
     pub risk_score: f64,
     pub created_at: u64,
+    /// For `Sandwich` opportunities, the profit-maximizing front-run size in
+    /// lamports found by [`MEVDetector::optimal_sandwich_size`], for
+    /// `create_sandwich_execution_plan` to build the real front/back
+    /// transactions from. `None` for every other strategy.
+    pub sandwich_front_run_lamports: Option<u64>,
+}
+
+impl MEVOpportunity {
+    /// Convert a raw opportunity as returned by `PumpSwapGrpcClient::get_mev_opportunities`
+    /// into the domain type the rest of the detector/execution pipeline works with. The
+    /// candidate's own transactions aren't transmitted over the wire, so `required_transactions`
+    /// stays empty here the same way it does for every locally-detected opportunity below, to be
+    /// populated once `signal_from_opportunity` builds an execution plan for it.
+    pub fn from_proto(raw: crate::proto::pumpswap::MEVOpportunity) -> Self {
+        Self {
+            id: raw.id,
+            strategy: match raw.strategy.as_str() {
+                "frontrun" => MEVStrategy::FrontRun,
+                "backrun" => MEVStrategy::BackRun,
+                "sandwich" => MEVStrategy::Sandwich,
+                "liquidation" => MEVStrategy::Liquidation,
+                _ => MEVStrategy::Arbitrage,
+            },
+            token_address: raw.token_address,
+            pool_address: raw.pool_address,
+            expected_profit: raw.expected_profit,
+            confidence_score: raw.confidence_score,
+            gas_estimate: raw.gas_estimate,
+            deadline: raw.deadline,
+            required_transactions: vec![],
+            risk_score: raw.risk_score,
+            created_at: raw.created_at,
+            sandwich_front_run_lamports: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -52,6 +109,11 @@ pub struct ExecutionPlan {
     pub max_slippage: f64,
     pub target_profit: f64,
     pub risk_mitigation: Vec<RiskMitigation>,
+    /// Account ordering `transactions`' eventual instruction(s) will need,
+    /// from [`MEVDetector::account_metas_for`] — resolved ahead of time so
+    /// building the real instructions is just attaching program data, not
+    /// also working out which accounts belong in which slot.
+    pub account_metas: Vec<AccountMeta>,
 }
 
 #[derive(Debug, Clone)]
@@ -61,6 +123,9 @@ pub enum RiskMitigation {
     MaxGasPrice { amount: u64 },
     Timeout { duration_ms: u64 },
     SlippageProtection { max_slippage: f64 },
+    /// Close out before the held position's collateral breaches this
+    /// fraction of its notional, per `crate::margin::liquidation_price`.
+    MaintenanceMargin { ratio: f64 },
 }
 
 pub struct MEVDetector {
@@ -68,6 +133,26 @@ pub struct MEVDetector {
     active_opportunities: HashMap<String, MEVOpportunity>,
     price_history: HashMap<String, Vec<PricePoint>>,
     pool_liquidity: HashMap<String, u64>,
+    /// Constant-product reserves per token address, fed from `pool_liquidity`
+    /// at listing time and refreshed from each `PriceUpdate`'s implied price,
+    /// so profit estimation can swap against real (if approximate) reserves
+    /// instead of guessing with RNG.
+    pool_reserves: HashMap<String, ConstantProductPool>,
+    /// Pool address each `token_address`'s `pool_reserves` entry was seeded
+    /// from, so a later `TokenListing` for the same token on a *different*
+    /// pool address is recognised as a second venue instead of overwriting
+    /// the first one's reserves.
+    primary_pool_address: HashMap<String, String>,
+    /// Reserves for a second venue of a token already tracked in
+    /// `pool_reserves`, once one has been observed. Only listing events seed
+    /// this (a `PriceUpdate` doesn't carry a pool address to disambiguate
+    /// which venue it describes), so it lags behind the primary pool's price
+    /// between listings — good enough to spot a real cross-pool arb.
+    secondary_pool_reserves: HashMap<String, ConstantProductPool>,
+    /// Rolling per-pool prioritization-fee samples backing the percentile
+    /// `MaxGasPrice` each `create_*_execution_plan` now asks for, in place
+    /// of a flat `Config::max_gas_price` regardless of live congestion.
+    priority_fee_oracle: PriorityFeeOracle,
     last_update: u64,
 }
 
@@ -85,18 +170,39 @@ impl MEVDetector {
             active_opportunities: HashMap::new(),
             price_history: HashMap::new(),
             pool_liquidity: HashMap::new(),
+            pool_reserves: HashMap::new(),
+            primary_pool_address: HashMap::new(),
+            secondary_pool_reserves: HashMap::new(),
+            priority_fee_oracle: PriorityFeeOracle::new(),
             last_update: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
         }
     }
-    
-    pub async fn analyze_opportunities(&mut self, token_listings: &[TokenListing], price_updates: &[PriceUpdate]) -> Result<Vec<MEVSignal>> {
+
+    /// Feeds one observed prioritization fee (micro-lamports per compute
+    /// unit) for `pool_address` into `priority_fee_oracle`, for the
+    /// submission layer to report back after each landed transaction so
+    /// `create_*_execution_plan`'s percentile fee stays current.
+    pub fn observe_priority_fee(&self, pool_address: &str, micro_lamports_per_cu: u64) {
+        self.priority_fee_oracle.observe_fee(pool_address, micro_lamports_per_cu);
+    }
+
+    /// `retriever` lets profit math pull live on-chain reserves for pools
+    /// this detector is already tracking instead of trusting `pool_reserves`'
+    /// last price-derived snapshot, which can be several updates stale by the
+    /// time a signal fires. A retriever with nothing for a given pool (e.g.
+    /// no account feed wired up yet at a call site) is treated the same as
+    /// having no live read at all — detection falls back to the tracked
+    /// snapshot rather than failing.
+    pub async fn analyze_opportunities(&mut self, token_listings: &[TokenListing], price_updates: &[PriceUpdate], retriever: &dyn PoolStateRetriever) -> Result<Vec<MEVSignal>> {
         let mut signals = Vec::new();
-        
+
         // Update price history
         self.update_price_history(price_updates).await;
-        
+
         // Analyze new token listings for arbitrage opportunities
         for listing in token_listings {
+            self.seed_pool_reserves(listing);
+            self.refresh_live_reserves(&listing.token_address, retriever);
             if let Some(signal) = self.analyze_new_listing(listing).await? {
                 signals.push(signal);
             }
@@ -104,6 +210,7 @@ impl MEVDetector {
         
         // Analyze price updates for MEV opportunities
         for price_update in price_updates {
+            self.refresh_live_reserves(&price_update.token_address, retriever);
             if let Some(signal) = self.analyze_price_update(price_update).await? {
                 signals.push(signal);
             }
@@ -125,7 +232,7 @@ impl MEVDetector {
         let config_guard = self.config.read().await;
         
         // Check if this token meets our criteria
-        if listing.initial_liquidity < (config_guard.min_liquidity * 1e9) as u64 {
+        if listing.initial_liquidity < config_guard.min_liquidity.0 {
             return Ok(None);
         }
         
@@ -186,15 +293,16 @@ impl MEVDetector {
                 pool_address: listing.pool_address.clone(),
                 expected_profit,
                 confidence_score: 0.8,
-                gas_estimate: 50000,
+                gas_estimate: PriorityFeeOracle::estimate_compute_units(MEVStrategy::FrontRun),
                 deadline: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() + 30,
                 required_transactions: vec![], // Would be populated with actual transactions
                 risk_score: 0.3,
                 created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+                sandwich_front_run_lamports: None,
             };
             
             let execution_plan = self.create_frontrun_execution_plan(&opportunity).await?;
-            let priority = self.calculate_priority(&opportunity);
+            let priority = self.calculate_priority(&opportunity).await;
             
             return Ok(Some(MEVSignal {
                 opportunity,
@@ -207,9 +315,17 @@ impl MEVDetector {
     }
     
     async fn detect_arbitrage_opportunity(&mut self, listing: &TokenListing) -> Result<Option<MEVSignal>> {
-        // Simulate arbitrage opportunity detection
-        let expected_profit = self.calculate_arbitrage_profit(listing).await?;
-        
+        // A second tracked venue for this token gives a genuine two-pool
+        // arbitrage signal; fall back to the single-pool round-trip estimate
+        // until one shows up.
+        let (expected_profit, confidence_score) = match Self::cross_pool_arbitrage_profit(
+            self.pool_reserves.get(&listing.token_address),
+            self.secondary_pool_reserves.get(&listing.token_address),
+        ) {
+            Some((profit, confidence)) => (profit, confidence),
+            None => (self.calculate_arbitrage_profit(listing).await?, 0.9),
+        };
+
         if expected_profit > 0.05 { // Minimum 0.05 SOL profit
             let opportunity = MEVOpportunity {
                 id: format!("arbitrage_{}", listing.token_address),
@@ -217,17 +333,18 @@ impl MEVDetector {
                 token_address: listing.token_address.clone(),
                 pool_address: listing.pool_address.clone(),
                 expected_profit,
-                confidence_score: 0.9,
-                gas_estimate: 100000,
+                confidence_score,
+                gas_estimate: PriorityFeeOracle::estimate_compute_units(MEVStrategy::Arbitrage),
                 deadline: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() + 60,
                 required_transactions: vec![], // Would be populated with actual transactions
                 risk_score: 0.2,
                 created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+                sandwich_front_run_lamports: None,
             };
-            
+
             let execution_plan = self.create_arbitrage_execution_plan(&opportunity).await?;
-            let priority = self.calculate_priority(&opportunity);
-            
+            let priority = self.calculate_priority(&opportunity).await;
+
             return Ok(Some(MEVSignal {
                 opportunity,
                 priority,
@@ -239,9 +356,12 @@ impl MEVDetector {
     }
     
     async fn detect_sandwich_opportunity(&mut self, price_update: &PriceUpdate) -> Result<Option<MEVSignal>> {
-        // Simulate sandwich attack opportunity detection
-        let expected_profit = self.calculate_sandwich_profit(price_update).await?;
-        
+        // Reject up front if we can't size a front-run at all: no tracked
+        // pool, no victim trade to ride, or no slippage signal to bound it.
+        let Some((expected_profit, front_run_lamports)) = self.calculate_sandwich_profit(price_update).await? else {
+            return Ok(None);
+        };
+
         if expected_profit > 0.2 { // Minimum 0.2 SOL profit
             let opportunity = MEVOpportunity {
                 id: format!("sandwich_{}", price_update.token_address),
@@ -250,15 +370,16 @@ impl MEVDetector {
                 pool_address: String::new(), // Would be populated
                 expected_profit,
                 confidence_score: 0.7,
-                gas_estimate: 150000,
+                gas_estimate: PriorityFeeOracle::estimate_compute_units(MEVStrategy::Sandwich),
                 deadline: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() + 10,
                 required_transactions: vec![], // Would be populated with actual transactions
                 risk_score: 0.6,
                 created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+                sandwich_front_run_lamports: Some(front_run_lamports),
             };
-            
+
             let execution_plan = self.create_sandwich_execution_plan(&opportunity).await?;
-            let priority = self.calculate_priority(&opportunity);
+            let priority = self.calculate_priority(&opportunity).await;
             
             return Ok(Some(MEVSignal {
                 opportunity,
@@ -282,15 +403,16 @@ impl MEVDetector {
                 pool_address: String::new(), // Would be populated
                 expected_profit,
                 confidence_score: 0.85,
-                gas_estimate: 80000,
+                gas_estimate: PriorityFeeOracle::estimate_compute_units(MEVStrategy::BackRun),
                 deadline: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() + 20,
                 required_transactions: vec![], // Would be populated with actual transactions
                 risk_score: 0.4,
                 created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+                sandwich_front_run_lamports: None,
             };
-            
+
             let execution_plan = self.create_backrun_execution_plan(&opportunity).await?;
-            let priority = self.calculate_priority(&opportunity);
+            let priority = self.calculate_priority(&opportunity).await;
             
             return Ok(Some(MEVSignal {
                 opportunity,
@@ -311,22 +433,254 @@ impl MEVDetector {
         Ok(base_profit * liquidity_factor * random_factor)
     }
     
+    /// Fallback used by `detect_arbitrage_opportunity` while only one venue
+    /// for this token is tracked (see `cross_pool_arbitrage_profit` for the
+    /// real two-venue case): the round-trip cost of buying `snipe_amount`
+    /// into this listing's pool and immediately selling the proceeds back
+    /// out. A single pool's fees make that round trip a loss (or
+    /// break-even) by construction, so this floors at zero rather than
+    /// reporting a profit no single pool can actually offer.
     async fn calculate_arbitrage_profit(&self, listing: &TokenListing) -> Result<f64> {
-        // Simulate arbitrage profit calculation
-        let base_profit = 0.3; // Base profit in SOL
-        let liquidity_factor = (listing.initial_liquidity as f64 / 1e9).min(50.0) / 50.0;
-        let random_factor = rand::random::<f64>() * 0.3 + 0.7; // 0.7 to 1.0
-        
-        Ok(base_profit * liquidity_factor * random_factor)
+        let Some(pool) = self.pool_reserves.get(&listing.token_address) else {
+            return Ok(0.0);
+        };
+
+        let snipe_amount = self.config.read().await.snipe_amount.0 as u128;
+        let tokens_out = pool.swap_output(snipe_amount, true);
+        if tokens_out == 0 {
+            return Ok(0.0);
+        }
+
+        let after_buy = pool.apply_swap(snipe_amount, true);
+        let sol_back = after_buy.swap_output(tokens_out, false);
+
+        let profit_lamports = sol_back as i128 - snipe_amount as i128;
+        Ok(profit_lamports.max(0) as f64 / LAMPORTS_PER_SOL as f64)
     }
-    
-    async fn calculate_sandwich_profit(&self, price_update: &PriceUpdate) -> Result<f64> {
-        // Simulate sandwich attack profit calculation
-        let volume_factor = (price_update.volume_1h / 10000.0).min(1.0);
-        let price_impact = (price_update.price_usd - price_update.price_usd * 0.95).abs() / price_update.price_usd;
-        let base_profit = 0.4; // Base profit in SOL
-        
-        Ok(base_profit * volume_factor * price_impact)
+
+    /// Genuine two-venue arbitrage between `primary` and `secondary`
+    /// reserves for the same token: buy in whichever pool is cheaper and
+    /// sell in the dearer one. Net profit as a function of the input size is
+    /// concave (each leg's price impact eats into the spread), so the
+    /// profit-maximizing input is found by ternary search over
+    /// `[0, smaller pool's SOL reserve]`. Returns `None` when there's no
+    /// second venue yet, the marginal prices don't diverge by more than
+    /// both legs' fees, or the optimum doesn't clear a profit after fees.
+    fn cross_pool_arbitrage_profit(
+        primary: Option<&ConstantProductPool>,
+        secondary: Option<&ConstantProductPool>,
+    ) -> Option<(f64, f64)> {
+        let primary = primary?;
+        let secondary = secondary?;
+
+        let (cheap, dear) = if primary.spot_price() <= secondary.spot_price() {
+            (primary, secondary)
+        } else {
+            (secondary, primary)
+        };
+
+        let cheap_price = cheap.spot_price();
+        let dear_price = dear.spot_price();
+        if cheap_price <= 0.0 || dear_price <= 0.0 {
+            return None;
+        }
+
+        // Not worth searching if the spread can't clear both legs' fees.
+        let spread = (dear_price - cheap_price) / cheap_price;
+        let fee_floor = 2.0 * cheap.fee_bps.max(dear.fee_bps) as f64 / 10_000.0;
+        if spread <= fee_floor {
+            return None;
+        }
+
+        let net_profit = |amount_in: u128| -> i128 {
+            let tokens_out = cheap.swap_output(amount_in, true);
+            if tokens_out == 0 {
+                return i128::MIN;
+            }
+            let sol_back = dear.swap_output(tokens_out, false);
+            sol_back as i128 - amount_in as i128 - 2 * BASE_TX_FEE_LAMPORTS as i128
+        };
+
+        let bound = cheap.reserve_sol.min(dear.reserve_sol);
+        let mut lo = 0u128;
+        let mut hi = bound;
+        for _ in 0..SANDWICH_SEARCH_ITERATIONS {
+            if hi <= lo {
+                break;
+            }
+            let third = (hi - lo) / 3;
+            let m1 = lo + third;
+            let m2 = hi - third;
+            if net_profit(m1) < net_profit(m2) {
+                lo = m1 + 1;
+            } else {
+                hi = m2;
+            }
+        }
+        let optimal_input = lo.min(bound);
+        let profit_lamports = net_profit(optimal_input);
+        if profit_lamports <= 0 {
+            return None;
+        }
+
+        // Confidence rises as the optimal trade closes the gap between the
+        // two pools: simulate it and compare the post-trade spot prices.
+        let after_cheap = cheap.apply_swap(optimal_input, true);
+        let tokens_out = cheap.swap_output(optimal_input, true);
+        let after_dear = dear.apply_swap(tokens_out, false);
+        let post_cheap_price = after_cheap.spot_price();
+        let post_dear_price = after_dear.spot_price();
+        let post_avg = (post_cheap_price + post_dear_price) / 2.0;
+        let post_divergence = if post_avg > 0.0 {
+            (post_dear_price - post_cheap_price).abs() / post_avg
+        } else {
+            1.0
+        };
+        let confidence_score = (1.0 - post_divergence).clamp(0.1, 0.99);
+
+        Some((profit_lamports as f64 / LAMPORTS_PER_SOL as f64, confidence_score))
+    }
+
+    /// Sizes and prices a sandwich of `price_update`'s pool: a front-run buy
+    /// sized by `optimal_sandwich_size` to maximize profit without pushing
+    /// the victim's own trade (sized from the last hour's volume) past their
+    /// inferred slippage tolerance, then a back-run sell once that victim
+    /// trade has pushed the price up. Returns `None` when there's no tracked
+    /// pool, no inferable victim slippage, or no feasible front-run size —
+    /// callers must reject the opportunity in all of those cases.
+    async fn calculate_sandwich_profit(&self, price_update: &PriceUpdate) -> Result<Option<(f64, u64)>> {
+        let Some(pool) = self.pool_reserves.get(&price_update.token_address) else {
+            return Ok(None);
+        };
+
+        let Some(max_slippage) = self.infer_victim_slippage(&price_update.token_address, price_update.price_usd) else {
+            return Ok(None);
+        };
+
+        let victim_sol = if price_update.price_usd > 0.0 {
+            price_update.volume_1h * price_update.price_sol / price_update.price_usd
+        } else {
+            0.0
+        };
+        let victim_lamports = (victim_sol * LAMPORTS_PER_SOL as f64).max(0.0) as u128;
+        if victim_lamports == 0 {
+            return Ok(None);
+        }
+
+        Ok(Self::optimal_sandwich_size(pool, victim_lamports, max_slippage)
+            .map(|(front_run_lamports, profit_sol)| (profit_sol, front_run_lamports)))
+    }
+
+    /// Infers the victim's effective slippage tolerance for `token_address`
+    /// from the price move between the two most recently recorded price
+    /// points: the fractional jump a trade just pushed the price through is
+    /// the best proxy available for the bound they'd accept, since
+    /// `PriceUpdate` carries no such field directly. `None` when there's no
+    /// prior price point to compare against yet (a fresh token has no
+    /// slippage signal), which the caller treats as "unknown, reject".
+    fn infer_victim_slippage(&self, token_address: &str, current_price_usd: f64) -> Option<f64> {
+        let history = self.price_history.get(token_address)?;
+        if history.len() < 2 || current_price_usd <= 0.0 {
+            return None;
+        }
+
+        let previous_price = history[history.len() - 2].price;
+        if previous_price <= 0.0 {
+            return None;
+        }
+
+        Some(((current_price_usd - previous_price) / previous_price).abs())
+    }
+
+    /// Finds the profit-maximizing front-run size `f*` (in lamports) for a
+    /// victim buy of `victim_sol` lamports into `pool`, subject to the
+    /// victim's realized price after their own trade staying within
+    /// `max_slippage` of the pre-front-run spot price.
+    ///
+    /// The victim's realized price only worsens as `f` grows, so the
+    /// slippage-feasible sizes form a prefix `[0, f_cap]` of the search
+    /// space; a binary search over `[0, victim_sol * SANDWICH_SEARCH_MULTIPLIER]`
+    /// finds that boundary. Net profit — buy low with the front-run, then
+    /// sell the proceeds back once the victim's trade has pushed the price
+    /// up, minus the two legs' base fees — is unimodal in `f` within that
+    /// range, so a ternary search over `[0, f_cap]` finds `f*` without
+    /// scanning every candidate. Returns `None` if no front-run is feasible
+    /// or the best one found isn't profitable.
+    fn optimal_sandwich_size(pool: &ConstantProductPool, victim_sol: u128, max_slippage: f64) -> Option<(u64, f64)> {
+        if victim_sol == 0 || max_slippage <= 0.0 {
+            return None;
+        }
+
+        let spot = pool.spot_price();
+        if spot <= 0.0 {
+            return None;
+        }
+        let max_victim_price = spot * (1.0 + max_slippage);
+
+        let victim_price_after = |front_run: u128| -> f64 {
+            let after_front = pool.apply_swap(front_run, true);
+            let tokens_out = after_front.swap_output(victim_sol, true);
+            if tokens_out == 0 {
+                return f64::INFINITY;
+            }
+            victim_sol as f64 / tokens_out as f64
+        };
+
+        if victim_price_after(0) > max_victim_price {
+            return None; // Victim is already over their slippage tolerance unattacked.
+        }
+
+        let search_bound = victim_sol.saturating_mul(SANDWICH_SEARCH_MULTIPLIER);
+        let mut feasible_lo = 0u128;
+        let mut infeasible_hi = search_bound;
+        for _ in 0..SANDWICH_SEARCH_ITERATIONS {
+            let mid = feasible_lo + (infeasible_hi - feasible_lo) / 2;
+            if victim_price_after(mid) <= max_victim_price {
+                feasible_lo = mid;
+            } else {
+                infeasible_hi = mid;
+            }
+        }
+        let f_cap = feasible_lo;
+        if f_cap == 0 {
+            return None;
+        }
+
+        let net_profit = |front_run: u128| -> f64 {
+            let tokens_bought = pool.swap_output(front_run, true);
+            if tokens_bought == 0 {
+                return f64::NEG_INFINITY;
+            }
+            let after_front = pool.apply_swap(front_run, true);
+            let after_victim = after_front.apply_swap(victim_sol, true);
+            let sol_back = after_victim.swap_output(tokens_bought, false);
+            let fees = 2 * BASE_TX_FEE_LAMPORTS as i128;
+            (sol_back as i128 - front_run as i128 - fees) as f64 / LAMPORTS_PER_SOL as f64
+        };
+
+        let mut lo = 0u128;
+        let mut hi = f_cap;
+        for _ in 0..SANDWICH_SEARCH_ITERATIONS {
+            if hi <= lo {
+                break;
+            }
+            let third = (hi - lo) / 3;
+            let m1 = lo + third;
+            let m2 = hi - third;
+            if net_profit(m1) < net_profit(m2) {
+                lo = m1 + 1;
+            } else {
+                hi = m2;
+            }
+        }
+        let f_star = lo.min(f_cap);
+        let profit = net_profit(f_star);
+
+        if f_star == 0 || profit <= 0.0 {
+            return None;
+        }
+
+        Some((f_star as u64, profit))
     }
     
     async fn calculate_backrun_profit(&self, price_update: &PriceUpdate) -> Result<f64> {
@@ -338,77 +692,196 @@ impl MEVDetector {
         Ok(base_profit * volume_factor * random_factor)
     }
     
+    /// Build a signal for an opportunity that came from `PumpSwapGrpcClient::get_mev_opportunities`
+    /// rather than from `analyze_opportunities`'s own listing/price-update heuristics. There's no
+    /// per-strategy execution plan to reuse for a raw gRPC opportunity, so this applies the same
+    /// generic risk mitigations every `create_*_execution_plan` helper below builds from, scaled
+    /// to the strategy's own gas estimate and the opportunity's deadline.
+    pub async fn signal_from_opportunity(&self, opportunity: MEVOpportunity) -> Result<MEVSignal> {
+        let config_guard = self.config.read().await;
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let duration_ms = opportunity.deadline.saturating_sub(now).saturating_mul(1000).max(1000);
+
+        let execution_plan = ExecutionPlan {
+            transactions: opportunity.required_transactions.clone(),
+            estimated_gas: opportunity.gas_estimate,
+            max_slippage: config_guard.max_slippage,
+            target_profit: opportunity.expected_profit,
+            risk_mitigation: vec![
+                RiskMitigation::MaxGasPrice { amount: config_guard.max_gas_price },
+                RiskMitigation::Timeout { duration_ms },
+                RiskMitigation::SlippageProtection { max_slippage: config_guard.max_slippage },
+            ],
+            account_metas: Self::account_metas_for(&opportunity),
+        };
+        drop(config_guard);
+
+        let priority = self.calculate_priority(&opportunity).await;
+
+        Ok(MEVSignal {
+            opportunity,
+            priority,
+            execution_plan,
+        })
+    }
+
+    /// Percentile of observed prioritization fees each strategy asks
+    /// `priority_fee_oracle` for. Time-critical strategies with a short
+    /// deadline (sandwich) request a higher percentile — worth overpaying to
+    /// land — than one with minutes to land (arbitrage).
+    fn fee_percentile_for(strategy: MEVStrategy) -> f64 {
+        match strategy {
+            MEVStrategy::Sandwich => 0.95,
+            MEVStrategy::FrontRun => 0.90,
+            MEVStrategy::Liquidation => 0.90,
+            MEVStrategy::BackRun => 0.85,
+            MEVStrategy::Arbitrage => 0.75,
+        }
+    }
+
+    /// `priority_fee_oracle`'s percentile fee for `opportunity`'s pool,
+    /// capped at `ceiling` and falling back to `ceiling` outright when the
+    /// oracle has no observations for that pool yet.
+    fn max_gas_price_for(&self, opportunity: &MEVOpportunity, ceiling: u64) -> u64 {
+        let percentile = Self::fee_percentile_for(opportunity.strategy);
+        self.priority_fee_oracle
+            .suggested_micro_lamports(&opportunity.pool_address, percentile)
+            .unwrap_or(ceiling)
+            .min(ceiling)
+    }
+
     async fn create_frontrun_execution_plan(&self, opportunity: &MEVOpportunity) -> Result<ExecutionPlan> {
         let config_guard = self.config.read().await;
-        
+        let max_gas_price = self.max_gas_price_for(opportunity, config_guard.max_gas_price);
+
         Ok(ExecutionPlan {
             transactions: vec![], // Would be populated with actual transactions
             estimated_gas: opportunity.gas_estimate,
             max_slippage: config_guard.max_slippage,
             target_profit: opportunity.expected_profit,
             risk_mitigation: vec![
-                RiskMitigation::MaxGasPrice { amount: config_guard.max_gas_price },
+                RiskMitigation::MaxGasPrice { amount: max_gas_price },
                 RiskMitigation::Timeout { duration_ms: 5000 },
                 RiskMitigation::SlippageProtection { max_slippage: config_guard.max_slippage },
             ],
+            account_metas: Self::account_metas_for(opportunity),
         })
     }
-    
+
     async fn create_arbitrage_execution_plan(&self, opportunity: &MEVOpportunity) -> Result<ExecutionPlan> {
         let config_guard = self.config.read().await;
-        
+        let max_gas_price = self.max_gas_price_for(opportunity, config_guard.max_gas_price);
+
         Ok(ExecutionPlan {
             transactions: vec![], // Would be populated with actual transactions
             estimated_gas: opportunity.gas_estimate,
             max_slippage: config_guard.max_slippage,
             target_profit: opportunity.expected_profit,
             risk_mitigation: vec![
-                RiskMitigation::MaxGasPrice { amount: config_guard.max_gas_price },
+                RiskMitigation::MaxGasPrice { amount: max_gas_price },
                 RiskMitigation::Timeout { duration_ms: 10000 },
                 RiskMitigation::SlippageProtection { max_slippage: config_guard.max_slippage * 0.5 },
             ],
+            account_metas: Self::account_metas_for(opportunity),
         })
     }
-    
+
     async fn create_sandwich_execution_plan(&self, opportunity: &MEVOpportunity) -> Result<ExecutionPlan> {
         let config_guard = self.config.read().await;
-        
+        let max_gas_price = self.max_gas_price_for(opportunity, config_guard.max_gas_price * 2);
+
         Ok(ExecutionPlan {
             transactions: vec![], // Would be populated with actual transactions
             estimated_gas: opportunity.gas_estimate,
             max_slippage: config_guard.max_slippage,
             target_profit: opportunity.expected_profit,
             risk_mitigation: vec![
-                RiskMitigation::MaxGasPrice { amount: config_guard.max_gas_price * 2 },
+                RiskMitigation::MaxGasPrice { amount: max_gas_price },
                 RiskMitigation::Timeout { duration_ms: 3000 },
                 RiskMitigation::SlippageProtection { max_slippage: config_guard.max_slippage * 2.0 },
             ],
+            account_metas: Self::account_metas_for(opportunity),
         })
     }
-    
+
     async fn create_backrun_execution_plan(&self, opportunity: &MEVOpportunity) -> Result<ExecutionPlan> {
         let config_guard = self.config.read().await;
-        
+        let max_gas_price = self.max_gas_price_for(opportunity, config_guard.max_gas_price);
+
         Ok(ExecutionPlan {
             transactions: vec![], // Would be populated with actual transactions
             estimated_gas: opportunity.gas_estimate,
             max_slippage: config_guard.max_slippage,
             target_profit: opportunity.expected_profit,
             risk_mitigation: vec![
-                RiskMitigation::MaxGasPrice { amount: config_guard.max_gas_price },
+                RiskMitigation::MaxGasPrice { amount: max_gas_price },
                 RiskMitigation::Timeout { duration_ms: 8000 },
                 RiskMitigation::SlippageProtection { max_slippage: config_guard.max_slippage },
             ],
+            account_metas: Self::account_metas_for(opportunity),
         })
     }
+
+    /// Account ordering the real instructions for `opportunity` will need:
+    /// the pool being traded against (writable, since a swap mutates its
+    /// reserves) followed by the token mint itself (read-only). `Sandwich`
+    /// trades the same pool twice — front-run leg then back-run leg — so
+    /// its ordering repeats the pool meta for the second leg. An address
+    /// that doesn't parse (e.g. `detect_sandwich_opportunity` and
+    /// `detect_backrun_opportunity` don't populate `pool_address` yet) just
+    /// drops that slot from the ordering rather than failing the plan.
+    fn account_metas_for(opportunity: &MEVOpportunity) -> Vec<AccountMeta> {
+        let pool_meta = Pubkey::from_str(&opportunity.pool_address).ok().map(|pool| AccountMeta::new(pool, false));
+        let mint_meta = Pubkey::from_str(&opportunity.token_address).ok().map(|mint| AccountMeta::new_readonly(mint, false));
+
+        let mut metas = Vec::new();
+        metas.extend(pool_meta.clone());
+        metas.extend(mint_meta);
+        if opportunity.strategy == MEVStrategy::Sandwich {
+            metas.extend(pool_meta);
+        }
+        metas
+    }
     
-    fn calculate_priority(&self, opportunity: &MEVOpportunity) -> MEVPriority {
+    /// Penalizes `risk_score` when the current price sits close to the
+    /// liquidation price a position this opportunity opens would carry,
+    /// given `Config::max_position_size` as the notional and
+    /// `Config::snipe_amount` as the collateral backing it (the bot's two
+    /// existing risk knobs double as entry/collateral for this purpose — see
+    /// `crate::margin::liquidation_price`). Opportunities in tokens this
+    /// detector isn't tracking reserves for fall back to the static
+    /// `risk_score` the caller set.
+    async fn calculate_priority(&self, opportunity: &MEVOpportunity) -> MEVPriority {
         let profit_score = opportunity.expected_profit;
         let confidence_score = opportunity.confidence_score;
-        let risk_score = opportunity.risk_score;
-        
+        let mut risk_score = opportunity.risk_score;
+
+        if let Some(pool) = self.pool_reserves.get(&opportunity.token_address) {
+            let current_price = pool.spot_price();
+            if current_price > 0.0 {
+                let config_guard = self.config.read().await;
+                let collateral = config_guard.snipe_amount.0 as f64;
+                let notional = config_guard.max_position_size.0 as f64;
+                let maintenance_margin = config_guard.maintenance_margin;
+                drop(config_guard);
+
+                let position_size = notional.max(collateral) / current_price;
+                let liq_price = liquidation_price(current_price, position_size, collateral, maintenance_margin);
+
+                if liq_price > 0.0 && liq_price < current_price {
+                    let distance = (current_price - liq_price) / current_price;
+                    let danger_zone = maintenance_margin * 3.0;
+                    if distance < danger_zone {
+                        let proximity = (1.0 - distance / danger_zone).clamp(0.0, 1.0);
+                        risk_score = (risk_score + proximity * 0.3).min(0.95);
+                    }
+                }
+            }
+        }
+
         let combined_score = profit_score * confidence_score * (1.0 - risk_score);
-        
+
         match combined_score {
             score if score > 0.8 => MEVPriority::Critical,
             score if score > 0.6 => MEVPriority::High,
@@ -424,11 +897,13 @@ impl MEVDetector {
                 timestamp: update.timestamp,
                 volume: update.volume_1h as u64,
             };
-            
+
             self.price_history
                 .entry(update.token_address.clone())
                 .or_insert_with(Vec::new)
                 .push(price_point);
+
+            self.refresh_pool_reserves(update);
         }
         
         // Keep only last 1000 price points per token
@@ -442,9 +917,80 @@ impl MEVDetector {
     async fn cleanup_old_opportunities(&mut self) {
         let current_time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
         let cutoff_time = current_time - 300; // Remove opportunities older than 5 minutes
-        
+
         self.active_opportunities.retain(|_, opportunity| {
             opportunity.created_at > cutoff_time
         });
     }
+
+    /// Seeds reserves for a freshly-listed token from `initial_liquidity`.
+    /// There's no price yet to split that liquidity into a SOL side and a
+    /// token side, so the pool starts at 1:1 parity; the first `PriceUpdate`
+    /// for this token replaces its primary entry with a reserve split implied
+    /// by the observed price, via `refresh_pool_reserves`.
+    ///
+    /// A listing for a token address we've already seeded, on a *different*
+    /// pool address, is a second venue for the same token rather than a
+    /// fresh one — it seeds `secondary_pool_reserves` instead, so
+    /// `detect_arbitrage_opportunity` can compare two real quotes.
+    fn seed_pool_reserves(&mut self, listing: &TokenListing) {
+        let reserve_sol = (listing.initial_liquidity as u128).max(1);
+        let pool = ConstantProductPool::new(reserve_sol, reserve_sol, POOL_FEE_BPS);
+        self.pool_liquidity.insert(listing.token_address.clone(), listing.initial_liquidity);
+
+        match self.primary_pool_address.get(&listing.token_address) {
+            Some(existing_pool_address) if existing_pool_address != &listing.pool_address => {
+                self.secondary_pool_reserves.insert(listing.token_address.clone(), pool);
+            }
+            _ => {
+                self.primary_pool_address.insert(listing.token_address.clone(), listing.pool_address.clone());
+                self.pool_reserves.insert(listing.token_address.clone(), pool);
+            }
+        }
+    }
+
+    /// Pulls `token_address`'s primary pool's reserves from `retriever` and
+    /// overwrites `pool_reserves` with them when available, so profit math
+    /// sees on-chain-fresh reserves rather than whatever `seed_pool_reserves`
+    /// / `refresh_pool_reserves` last derived from listing/price-update data
+    /// alone. Leaves the tracked snapshot untouched if the pool address
+    /// doesn't parse or `retriever` has nothing for it (e.g. it wasn't in
+    /// this call's supplied account set) — this is a best-effort refresh,
+    /// not a required one.
+    fn refresh_live_reserves(&mut self, token_address: &str, retriever: &dyn PoolStateRetriever) {
+        let Some(pool_address) = self.primary_pool_address.get(token_address) else {
+            return;
+        };
+        let Ok(pool_pubkey) = Pubkey::from_str(pool_address) else {
+            return;
+        };
+        let Ok((reserve_in, reserve_out)) = retriever.reserves(&pool_pubkey) else {
+            return;
+        };
+
+        self.pool_reserves.insert(token_address.to_string(), ConstantProductPool::new(reserve_in, reserve_out, POOL_FEE_BPS));
+    }
+
+    /// Refreshes `pool_reserves` for `update.token_address` from its
+    /// observed `price_usd`/`price_sol`/`liquidity_usd`, splitting
+    /// `liquidity_usd` evenly between the SOL and token sides (the AMM
+    /// invariant always holds both sides at equal USD value at the current
+    /// price) and converting each side to lamports/base-units so the
+    /// resulting pool's spot price matches `price_sol` exactly.
+    fn refresh_pool_reserves(&mut self, update: &PriceUpdate) {
+        if update.price_usd <= 0.0 || update.price_sol <= 0.0 || update.liquidity_usd <= 0.0 {
+            return;
+        }
+
+        let side_usd = update.liquidity_usd / 2.0;
+        let whole_tokens = side_usd / update.price_usd;
+        let reserve_token = (whole_tokens * LAMPORTS_PER_SOL as f64).max(1.0) as u128;
+        let reserve_sol = (reserve_token as f64 * update.price_sol).max(1.0) as u128;
+
+        self.pool_reserves.insert(
+            update.token_address.clone(),
+            ConstantProductPool::new(reserve_sol, reserve_token, POOL_FEE_BPS),
+        );
+    }
+
 }
@@ -0,0 +1,166 @@
+use primitive_types::U256;
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// An exact lamport amount. Config used to carry SOL-denominated fields as
+/// `f64`, which meant `min_liquidity * 1e9` and friends silently lost
+/// precision and could round differently depending on call order. `Lamports`
+/// stores the raw integer instead, so downstream comparisons and arithmetic
+/// (`listing.initial_liquidity < config.min_liquidity.0`) are exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+pub struct Lamports(pub u64);
+
+impl Lamports {
+    pub const ZERO: Lamports = Lamports(0);
+
+    pub fn from_sol(sol: f64) -> Self {
+        Self((sol * LAMPORTS_PER_SOL as f64).round() as u64)
+    }
+
+    pub fn as_sol(self) -> f64 {
+        self.0 as f64 / LAMPORTS_PER_SOL as f64
+    }
+
+    pub fn checked_add(self, other: Lamports) -> Option<Lamports> {
+        self.0.checked_add(other.0).map(Lamports)
+    }
+
+    pub fn checked_sub(self, other: Lamports) -> Option<Lamports> {
+        self.0.checked_sub(other.0).map(Lamports)
+    }
+
+    /// `self * numerator / denominator`, computed in `u128` so a slippage or
+    /// fee calculation (e.g. `amount.mul_div(risk_percentage_bps, 10_000)`)
+    /// never overflows `u64` before the division.
+    pub fn mul_div(self, numerator: u64, denominator: u64) -> Option<Lamports> {
+        if denominator == 0 {
+            return None;
+        }
+        let product = (self.0 as u128).checked_mul(numerator as u128)?;
+        u64::try_from(product / denominator as u128).ok().map(Lamports)
+    }
+}
+
+impl fmt::Display for Lamports {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.9} SOL", self.as_sol())
+    }
+}
+
+/// Accepts either a decimal SOL string (`"10.5"`), a raw lamport integer
+/// string, or a bare integer in TOML, so existing `config.toml` files with
+/// `min_liquidity = 10.0` keep loading while new ones can specify the exact
+/// lamport count directly.
+impl Serialize for Lamports {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.as_sol())
+    }
+}
+
+impl<'de> Deserialize<'de> for Lamports {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(f64),
+            Text(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Number(sol) => Ok(Lamports::from_sol(sol)),
+            Raw::Text(text) => {
+                if let Ok(lamports) = text.parse::<u64>() {
+                    Ok(Lamports(lamports))
+                } else {
+                    let sol = text.parse::<f64>().map_err(DeError::custom)?;
+                    Ok(Lamports::from_sol(sol))
+                }
+            }
+        }
+    }
+}
+
+/// A fixed-point decimal amount for SPL token amounts, which (unlike SOL's
+/// fixed 9) can carry any number of decimals. Backed by `U256` so a trade
+/// size for an 18-decimal token doesn't need to round during intermediate
+/// arithmetic the way an `f64` or even a `u128` eventually would.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedU256 {
+    /// Raw value in the token's smallest unit.
+    pub value: U256,
+    pub decimals: u8,
+}
+
+impl FixedU256 {
+    pub fn new(value: U256, decimals: u8) -> Self {
+        Self { value, decimals }
+    }
+
+    /// Parses a decimal string like `"10.5"` into its smallest-unit
+    /// representation at `decimals` precision, truncating (not rounding) any
+    /// extra fractional digits.
+    pub fn from_decimal_str(input: &str, decimals: u8) -> anyhow::Result<Self> {
+        let (whole, frac) = input.split_once('.').unwrap_or((input, ""));
+
+        let whole: U256 = if whole.is_empty() {
+            U256::zero()
+        } else {
+            U256::from_dec_str(whole).map_err(|e| anyhow::anyhow!("invalid amount: {}", e))?
+        };
+
+        let mut frac_digits = frac.to_string();
+        frac_digits.truncate(decimals as usize);
+        while frac_digits.len() < decimals as usize {
+            frac_digits.push('0');
+        }
+        let frac_value: U256 = if frac_digits.is_empty() {
+            U256::zero()
+        } else {
+            U256::from_dec_str(&frac_digits).map_err(|e| anyhow::anyhow!("invalid amount: {}", e))?
+        };
+
+        let scale = U256::from(10u64).pow(U256::from(decimals));
+        Ok(Self { value: whole * scale + frac_value, decimals })
+    }
+
+    pub fn checked_add(self, other: FixedU256) -> Option<FixedU256> {
+        if self.decimals != other.decimals {
+            return None;
+        }
+        self.value.checked_add(other.value).map(|value| FixedU256 { value, decimals: self.decimals })
+    }
+
+    pub fn checked_sub(self, other: FixedU256) -> Option<FixedU256> {
+        if self.decimals != other.decimals {
+            return None;
+        }
+        self.value.checked_sub(other.value).map(|value| FixedU256 { value, decimals: self.decimals })
+    }
+
+    /// `self * numerator / denominator`, multiplying in full 256-bit width
+    /// before dividing so a price-impact or slippage calculation never
+    /// overflows partway through.
+    pub fn mul_div(self, numerator: U256, denominator: U256) -> Option<FixedU256> {
+        if denominator.is_zero() {
+            return None;
+        }
+        self.value
+            .checked_mul(numerator)
+            .map(|product| FixedU256 { value: product / denominator, decimals: self.decimals })
+    }
+
+    /// Lossy conversion for display/logging only; never use the result as
+    /// input to further arithmetic.
+    pub fn as_f64(self) -> f64 {
+        let scale = 10f64.powi(self.decimals as i32);
+        self.value.to_string().parse::<f64>().unwrap_or(0.0) / scale
+    }
+}
+
+impl fmt::Display for FixedU256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.*}", self.decimals as usize, self.as_f64())
+    }
+}
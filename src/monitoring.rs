@@ -1,16 +1,70 @@
 use anyhow::Result;
-use log::{debug, error, info, warn};
+use hdrhistogram::Histogram;
+use tracing::{debug, error, info, warn};
 use metrics::{counter, gauge, histogram, register_counter, register_gauge, register_histogram};
 use metrics_exporter_prometheus::PrometheusBuilder;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::RwLock;
 
 use crate::config::Config;
+use crate::error_tracking::ErrorTracker;
+
+/// `execution_latency_ms`/`bundle_confirmation_time_ms` are recorded into
+/// their HDR histograms in microseconds (ms * this scale) so fractional
+/// millisecond latencies aren't lost to integer rounding.
+const HDR_MS_SCALE: f64 = 1000.0;
+/// `price_impact_percentage`/`slippage_percentage` are recorded in basis
+/// points (percentage * this scale) for the same reason.
+const HDR_PCT_SCALE: f64 = 100.0;
+
+/// Latest depth reported by [`Monitoring::update_queue_size`], read back by
+/// `get_queue_size` for the performance-collection tick. Plain atomic rather
+/// than a lazy_static gauge handle since the same number both feeds the
+/// Prometheus gauge and needs to be readable synchronously from here.
+static QUEUE_DEPTH: AtomicUsize = AtomicUsize::new(0);
+
+/// p50/p90/p99/p99.9/max read out of an HDR histogram, already converted
+/// back out of its integer recording units.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PercentileSnapshot {
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+    pub p999: f64,
+    pub max: f64,
+    pub samples: u64,
+}
+
+impl PercentileSnapshot {
+    fn from_histogram(histogram: &Histogram<u64>, scale: f64) -> Self {
+        Self {
+            p50: histogram.value_at_quantile(0.50) as f64 / scale,
+            p90: histogram.value_at_quantile(0.90) as f64 / scale,
+            p99: histogram.value_at_quantile(0.99) as f64 / scale,
+            p999: histogram.value_at_quantile(0.999) as f64 / scale,
+            max: histogram.max() as f64 / scale,
+            samples: histogram.len(),
+        }
+    }
+}
+
+/// Point-in-time tail-latency read-out for the four HDR-tracked metrics, so
+/// callers like a risk gate can react to tail latency without scraping
+/// Prometheus.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LatencySnapshot {
+    pub execution_latency_ms: PercentileSnapshot,
+    pub bundle_confirmation_time_ms: PercentileSnapshot,
+    pub price_impact_percentage: PercentileSnapshot,
+    pub slippage_percentage: PercentileSnapshot,
+}
 
 pub struct Monitoring {
     config: Arc<RwLock<Config>>,
     metrics_server: Option<tokio::task::JoinHandle<()>>,
+    error_tracker: Arc<ErrorTracker>,
 }
 
 // Metrics
@@ -27,52 +81,135 @@ lazy_static::lazy_static! {
     static ref BUNDLE_CONFIRMATION_TIME: metrics::Histogram = register_histogram!("bundle_confirmation_time_ms", "Bundle confirmation time in milliseconds");
     static ref PRICE_IMPACT: metrics::Histogram = register_histogram!("price_impact_percentage", "Price impact percentage");
     static ref SLIPPAGE: metrics::Histogram = register_histogram!("slippage_percentage", "Slippage percentage");
+    static ref LISTING_STREAM_RECONNECTS: metrics::Counter = register_counter!("listing_stream_reconnects_total", "Total number of times the token listing stream has reconnected");
+    static ref PRICE_STREAM_RECONNECTS: metrics::Counter = register_counter!("price_stream_reconnects_total", "Total number of times the price update stream has reconnected");
+    static ref LISTING_STREAM_CONNECTED: metrics::Gauge = register_gauge!("listing_stream_connected", "1 if the token listing stream is currently connected, 0 otherwise");
+    static ref PRICE_STREAM_CONNECTED: metrics::Gauge = register_gauge!("price_stream_connected", "1 if the price update stream is currently connected, 0 otherwise");
+    static ref SUPPRESSED_TOKENS: metrics::Gauge = register_gauge!("error_tracker_suppressed_tokens", "Number of (token, operation) pairs currently inside their error-cooldown window");
+
+    // Direct-TPU QUIC submission errors, split by failure mode since QUIC
+    // failures are otherwise silent and indistinguishable from each other.
+    static ref TPU_QUIC_CONNECT_ERRORS: metrics::Counter = register_counter!("tpu_quic_connect_errors_total", "Total QUIC connection-establishment failures to leader TPU ports");
+    static ref TPU_QUIC_WRITE_ERRORS: metrics::Counter = register_counter!("tpu_quic_write_errors_total", "Total QUIC stream-write failures to leader TPU ports");
+    static ref TPU_QUIC_TIMEOUTS: metrics::Counter = register_counter!("tpu_quic_timeouts_total", "Total QUIC connect/write operations that exceeded their timeout");
+    static ref TPU_QUIC_STALE_LEADER_DROPS: metrics::Counter = register_counter!("tpu_quic_stale_leader_drops_total", "Total packets dropped because the target leader rotated out of the upcoming window before send");
+
+    // HDR histograms backing precise percentile reads for the coarse-bucketed
+    // Prometheus histograms above. Bounds are generous (up to an hour / a
+    // 10000% move) since hdrhistogram's memory cost depends only on the
+    // bound/precision, not the sample count.
+    static ref EXECUTION_LATENCY_HDR: StdMutex<Histogram<u64>> =
+        StdMutex::new(Histogram::new_with_bounds(1, 3_600_000_000, 3).unwrap());
+    static ref BUNDLE_CONFIRMATION_HDR: StdMutex<Histogram<u64>> =
+        StdMutex::new(Histogram::new_with_bounds(1, 3_600_000_000, 3).unwrap());
+    static ref PRICE_IMPACT_HDR: StdMutex<Histogram<u64>> =
+        StdMutex::new(Histogram::new_with_bounds(1, 1_000_000, 3).unwrap());
+    static ref SLIPPAGE_HDR: StdMutex<Histogram<u64>> =
+        StdMutex::new(Histogram::new_with_bounds(1, 1_000_000, 3).unwrap());
+
+    static ref EXECUTION_LATENCY_P50: metrics::Gauge = register_gauge!("execution_latency_p50_ms", "p50 execution latency in milliseconds (HDR)");
+    static ref EXECUTION_LATENCY_P90: metrics::Gauge = register_gauge!("execution_latency_p90_ms", "p90 execution latency in milliseconds (HDR)");
+    static ref EXECUTION_LATENCY_P99: metrics::Gauge = register_gauge!("execution_latency_p99_ms", "p99 execution latency in milliseconds (HDR)");
+    static ref EXECUTION_LATENCY_P999: metrics::Gauge = register_gauge!("execution_latency_p999_ms", "p99.9 execution latency in milliseconds (HDR)");
+    static ref EXECUTION_LATENCY_MAX: metrics::Gauge = register_gauge!("execution_latency_max_ms", "Max execution latency in milliseconds (HDR)");
+
+    static ref BUNDLE_CONFIRMATION_P50: metrics::Gauge = register_gauge!("bundle_confirmation_time_p50_ms", "p50 bundle confirmation time in milliseconds (HDR)");
+    static ref BUNDLE_CONFIRMATION_P90: metrics::Gauge = register_gauge!("bundle_confirmation_time_p90_ms", "p90 bundle confirmation time in milliseconds (HDR)");
+    static ref BUNDLE_CONFIRMATION_P99: metrics::Gauge = register_gauge!("bundle_confirmation_time_p99_ms", "p99 bundle confirmation time in milliseconds (HDR)");
+    static ref BUNDLE_CONFIRMATION_P999: metrics::Gauge = register_gauge!("bundle_confirmation_time_p999_ms", "p99.9 bundle confirmation time in milliseconds (HDR)");
+    static ref BUNDLE_CONFIRMATION_MAX: metrics::Gauge = register_gauge!("bundle_confirmation_time_max_ms", "Max bundle confirmation time in milliseconds (HDR)");
+
+    static ref PRICE_IMPACT_P50: metrics::Gauge = register_gauge!("price_impact_p50_percentage", "p50 price impact percentage (HDR)");
+    static ref PRICE_IMPACT_P90: metrics::Gauge = register_gauge!("price_impact_p90_percentage", "p90 price impact percentage (HDR)");
+    static ref PRICE_IMPACT_P99: metrics::Gauge = register_gauge!("price_impact_p99_percentage", "p99 price impact percentage (HDR)");
+    static ref PRICE_IMPACT_P999: metrics::Gauge = register_gauge!("price_impact_p999_percentage", "p99.9 price impact percentage (HDR)");
+    static ref PRICE_IMPACT_MAX: metrics::Gauge = register_gauge!("price_impact_max_percentage", "Max price impact percentage (HDR)");
+
+    static ref SLIPPAGE_P50: metrics::Gauge = register_gauge!("slippage_p50_percentage", "p50 slippage percentage (HDR)");
+    static ref SLIPPAGE_P90: metrics::Gauge = register_gauge!("slippage_p90_percentage", "p90 slippage percentage (HDR)");
+    static ref SLIPPAGE_P99: metrics::Gauge = register_gauge!("slippage_p99_percentage", "p99 slippage percentage (HDR)");
+    static ref SLIPPAGE_P999: metrics::Gauge = register_gauge!("slippage_p999_percentage", "p99.9 slippage percentage (HDR)");
+    static ref SLIPPAGE_MAX: metrics::Gauge = register_gauge!("slippage_max_percentage", "Max slippage percentage (HDR)");
+}
+
+/// Builds a `tokio::time::Interval` that avoids the thundering-herd effect
+/// `tokio::time::interval`'s defaults invite: `MissedTickBehavior::Delay`
+/// means a collection call that runs long pushes the next tick back by
+/// `period` after it *finishes*, instead of bursting to catch up, and a
+/// small random jitter on the first tick keeps the system/performance/risk
+/// loops from all firing on the same aligned boundary and hammering the
+/// RPC/gRPC backends at once.
+fn delay_interval(period: Duration) -> tokio::time::Interval {
+    let jitter_ms = {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let max_jitter_ms = (period.as_millis() as u64 / 10).max(1);
+        rng.gen_range(0..=max_jitter_ms)
+    };
+
+    let mut interval = tokio::time::interval_at(
+        tokio::time::Instant::now() + Duration::from_millis(jitter_ms),
+        period,
+    );
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    interval
 }
 
 impl Monitoring {
     pub async fn new(config: Arc<RwLock<Config>>) -> Result<Self> {
         let config_guard = config.read().await;
-        
+        let error_tracker = Arc::new(ErrorTracker::new(config.clone()));
+
         let mut monitoring = Self {
             config,
             metrics_server: None,
+            error_tracker,
         };
-        
+
         // Start metrics server if enabled
         if config_guard.enable_metrics {
             monitoring.start_metrics_server().await?;
         }
-        
+
         Ok(monitoring)
     }
-    
+
+    /// Shared handle to the per-token/operation error tracker, so callers
+    /// that need to check `should_skip` or record outcomes (stream
+    /// callbacks, `TradeLogger`) use the same state this monitoring loop
+    /// reports metrics from.
+    pub fn error_tracker(&self) -> Arc<ErrorTracker> {
+        self.error_tracker.clone()
+    }
+
     pub async fn start(&mut self) -> Result<()> {
         info!("Starting monitoring system");
-        
+
         // Start system monitoring
         let system_monitor_handle = {
             let config = self.config.clone();
             tokio::spawn(async move {
-                let mut interval = tokio::time::interval(Duration::from_secs(10));
+                let mut interval = delay_interval(Duration::from_secs(10));
                 loop {
                     interval.tick().await;
-                    
+
                     if let Err(e) = Self::collect_system_metrics(&config).await {
                         error!("Error collecting system metrics: {}", e);
                     }
                 }
             })
         };
-        
+
         // Start performance monitoring
         let performance_monitor_handle = {
             let config = self.config.clone();
+            let error_tracker = self.error_tracker.clone();
             tokio::spawn(async move {
-                let mut interval = tokio::time::interval(Duration::from_secs(5));
+                let mut interval = delay_interval(Duration::from_secs(5));
                 loop {
                     interval.tick().await;
-                    
-                    if let Err(e) = Self::collect_performance_metrics(&config).await {
+
+                    if let Err(e) = Self::collect_performance_metrics(&config, &error_tracker).await {
                         error!("Error collecting performance metrics: {}", e);
                     }
                 }
@@ -83,10 +220,10 @@ impl Monitoring {
         let risk_monitor_handle = {
             let config = self.config.clone();
             tokio::spawn(async move {
-                let mut interval = tokio::time::interval(Duration::from_secs(1));
+                let mut interval = delay_interval(Duration::from_secs(1));
                 loop {
                     interval.tick().await;
-                    
+
                     if let Err(e) = Self::monitor_risk_metrics(&config).await {
                         error!("Error monitoring risk metrics: {}", e);
                     }
@@ -151,22 +288,25 @@ impl Monitoring {
         Ok(())
     }
     
-    async fn collect_performance_metrics(config: &Arc<RwLock<Config>>) -> Result<()> {
+    async fn collect_performance_metrics(config: &Arc<RwLock<Config>>, error_tracker: &Arc<ErrorTracker>) -> Result<()> {
         let config_guard = config.read().await;
-        
+
         // Collect performance metrics
         let active_connections = Self::get_active_connections().await?;
         let queue_size = Self::get_queue_size().await?;
-        let error_rate = Self::get_error_rate().await?;
-        
+        let error_rate = error_tracker.error_rate();
+
         // Update metrics
         gauge!("active_connections", active_connections as f64);
         gauge!("queue_size", queue_size as f64);
         gauge!("error_rate_percentage", error_rate);
-        
-        debug!("Performance metrics collected: connections={}, queue={}, error_rate={}%", 
+        gauge!(SUPPRESSED_TOKENS, error_tracker.suppressed_count().await as f64);
+
+        debug!("Performance metrics collected: connections={}, queue={}, error_rate={}%",
                active_connections, queue_size, error_rate);
-        
+
+        Self::publish_latency_percentiles();
+
         Ok(())
     }
     
@@ -175,19 +315,19 @@ impl Monitoring {
         
         // Check risk limits
         let daily_pnl = Self::get_daily_pnl().await?;
-        let max_daily_loss = config_guard.max_daily_loss;
-        let max_position_size = config_guard.max_position_size;
-        
+        let max_daily_loss = config_guard.max_daily_loss.as_sol();
+        let max_position_size = config_guard.max_position_size.as_sol();
+
         // Update metrics
         gauge!("daily_pnl_sol", daily_pnl);
         gauge!("max_daily_loss_sol", max_daily_loss);
         gauge!("max_position_size_sol", max_position_size);
-        
+
         // Check if we're approaching risk limits
         if daily_pnl < -max_daily_loss * 0.8 {
             warn!("Approaching daily loss limit: {} SOL (limit: {} SOL)", daily_pnl, max_daily_loss);
         }
-        
+
         if daily_pnl < -max_daily_loss {
             error!("Daily loss limit exceeded: {} SOL (limit: {} SOL)", daily_pnl, max_daily_loss);
             // In a real implementation, this would trigger risk management actions
@@ -228,21 +368,133 @@ impl Monitoring {
     pub fn update_active_trades(count: usize) {
         gauge!(ACTIVE_TRADES, count as f64);
     }
+
+    /// Called by `TradeQueue::push` so the `queue_size` gauge reflects the
+    /// real channel depth immediately instead of waiting for the next
+    /// performance-collection tick to poll it.
+    pub fn update_queue_size(depth: usize) {
+        QUEUE_DEPTH.store(depth, Ordering::Relaxed);
+        gauge!("queue_size", depth as f64);
+    }
     
     pub fn record_execution_latency(latency_ms: f64) {
         histogram!(LATENCY_MS, latency_ms);
+        Self::record_hdr(&EXECUTION_LATENCY_HDR, latency_ms, HDR_MS_SCALE);
     }
-    
+
     pub fn record_bundle_confirmation_time(time_ms: f64) {
         histogram!(BUNDLE_CONFIRMATION_TIME, time_ms);
+        Self::record_hdr(&BUNDLE_CONFIRMATION_HDR, time_ms, HDR_MS_SCALE);
     }
-    
+
     pub fn record_price_impact(impact_percentage: f64) {
         histogram!(PRICE_IMPACT, impact_percentage);
+        Self::record_hdr(&PRICE_IMPACT_HDR, impact_percentage, HDR_PCT_SCALE);
     }
-    
+
     pub fn record_slippage(slippage_percentage: f64) {
         histogram!(SLIPPAGE, slippage_percentage);
+        Self::record_hdr(&SLIPPAGE_HDR, slippage_percentage, HDR_PCT_SCALE);
+    }
+
+    fn record_hdr(recorder: &StdMutex<Histogram<u64>>, value: f64, scale: f64) {
+        let scaled = (value.max(0.0) * scale).round() as u64;
+        if let Ok(mut histogram) = recorder.lock() {
+            let _ = histogram.record(scaled.max(1));
+        }
+    }
+
+    /// Current p50/p90/p99/p99.9/max for each HDR-tracked metric, for
+    /// callers (e.g. a risk gate) that need to react to tail latency
+    /// without scraping Prometheus.
+    pub fn latency_snapshot() -> LatencySnapshot {
+        let read = |recorder: &StdMutex<Histogram<u64>>, scale: f64| {
+            recorder
+                .lock()
+                .map(|histogram| PercentileSnapshot::from_histogram(&histogram, scale))
+                .unwrap_or_default()
+        };
+
+        LatencySnapshot {
+            execution_latency_ms: read(&EXECUTION_LATENCY_HDR, HDR_MS_SCALE),
+            bundle_confirmation_time_ms: read(&BUNDLE_CONFIRMATION_HDR, HDR_MS_SCALE),
+            price_impact_percentage: read(&PRICE_IMPACT_HDR, HDR_PCT_SCALE),
+            slippage_percentage: read(&SLIPPAGE_HDR, HDR_PCT_SCALE),
+        }
+    }
+
+    /// Publish the current HDR percentiles for every tracked metric as
+    /// Prometheus gauges; called once per performance-collection tick.
+    fn publish_latency_percentiles() {
+        let snapshot = Self::latency_snapshot();
+
+        gauge!(EXECUTION_LATENCY_P50, snapshot.execution_latency_ms.p50);
+        gauge!(EXECUTION_LATENCY_P90, snapshot.execution_latency_ms.p90);
+        gauge!(EXECUTION_LATENCY_P99, snapshot.execution_latency_ms.p99);
+        gauge!(EXECUTION_LATENCY_P999, snapshot.execution_latency_ms.p999);
+        gauge!(EXECUTION_LATENCY_MAX, snapshot.execution_latency_ms.max);
+
+        gauge!(BUNDLE_CONFIRMATION_P50, snapshot.bundle_confirmation_time_ms.p50);
+        gauge!(BUNDLE_CONFIRMATION_P90, snapshot.bundle_confirmation_time_ms.p90);
+        gauge!(BUNDLE_CONFIRMATION_P99, snapshot.bundle_confirmation_time_ms.p99);
+        gauge!(BUNDLE_CONFIRMATION_P999, snapshot.bundle_confirmation_time_ms.p999);
+        gauge!(BUNDLE_CONFIRMATION_MAX, snapshot.bundle_confirmation_time_ms.max);
+
+        gauge!(PRICE_IMPACT_P50, snapshot.price_impact_percentage.p50);
+        gauge!(PRICE_IMPACT_P90, snapshot.price_impact_percentage.p90);
+        gauge!(PRICE_IMPACT_P99, snapshot.price_impact_percentage.p99);
+        gauge!(PRICE_IMPACT_P999, snapshot.price_impact_percentage.p999);
+        gauge!(PRICE_IMPACT_MAX, snapshot.price_impact_percentage.max);
+
+        gauge!(SLIPPAGE_P50, snapshot.slippage_percentage.p50);
+        gauge!(SLIPPAGE_P90, snapshot.slippage_percentage.p90);
+        gauge!(SLIPPAGE_P99, snapshot.slippage_percentage.p99);
+        gauge!(SLIPPAGE_P999, snapshot.slippage_percentage.p999);
+        gauge!(SLIPPAGE_MAX, snapshot.slippage_percentage.max);
+    }
+
+    pub fn record_listing_stream_reconnect() {
+        counter!(LISTING_STREAM_RECONNECTS, 1.0);
+    }
+
+    pub fn record_price_stream_reconnect() {
+        counter!(PRICE_STREAM_RECONNECTS, 1.0);
+    }
+
+    pub fn update_listing_stream_connected(connected: bool) {
+        gauge!(LISTING_STREAM_CONNECTED, if connected { 1.0 } else { 0.0 });
+    }
+
+    pub fn update_price_stream_connected(connected: bool) {
+        gauge!(PRICE_STREAM_CONNECTED, if connected { 1.0 } else { 0.0 });
+    }
+
+    pub fn record_tpu_quic_connect_error() {
+        counter!(TPU_QUIC_CONNECT_ERRORS, 1.0);
+    }
+
+    pub fn record_tpu_quic_write_error() {
+        counter!(TPU_QUIC_WRITE_ERRORS, 1.0);
+    }
+
+    pub fn record_tpu_quic_timeout() {
+        counter!(TPU_QUIC_TIMEOUTS, 1.0);
+    }
+
+    pub fn record_tpu_quic_stale_leader_drop() {
+        counter!(TPU_QUIC_STALE_LEADER_DROPS, 1.0);
+    }
+
+    /// Per-token/operation consecutive-failure count, labeled so each
+    /// tracked pair gets its own series instead of collapsing into a single
+    /// aggregate gauge.
+    pub fn update_token_failure_count(token: &str, operation: &str, count: u32) {
+        gauge!(
+            "error_tracker_consecutive_failures",
+            count as f64,
+            "token" => token.to_string(),
+            "operation" => operation.to_string()
+        );
     }
     
     // System monitoring helper functions
@@ -271,15 +523,7 @@ impl Monitoring {
     }
     
     async fn get_queue_size() -> Result<usize> {
-        // This would get actual queue size
-        // For now, return a simulated value
-        Ok(0)
-    }
-    
-    async fn get_error_rate() -> Result<f64> {
-        // This would calculate actual error rate
-        // For now, return a simulated value
-        Ok(0.1) // 0.1%
+        Ok(QUEUE_DEPTH.load(Ordering::Relaxed))
     }
     
     async fn get_daily_pnl() -> Result<f64> {
@@ -289,74 +533,109 @@ impl Monitoring {
     }
 }
 
+/// Operation kind recorded against `ErrorTracker` for trades logged through
+/// `TradeLogger`; stream callbacks that also feed the tracker use their own
+/// kinds ("snipe", "mev", "exit") so a token's failure streak is scoped to
+/// the operation that's actually failing.
+const TRADE_OPERATION: &str = "trade";
+
 pub struct TradeLogger {
     config: Arc<RwLock<Config>>,
+    error_tracker: Arc<ErrorTracker>,
 }
 
 impl TradeLogger {
-    pub fn new(config: Arc<RwLock<Config>>) -> Self {
-        Self { config }
+    pub fn new(config: Arc<RwLock<Config>>, error_tracker: Arc<ErrorTracker>) -> Self {
+        Self { config, error_tracker }
     }
-    
+
     pub fn log_trade_start(&self, token_address: &str, amount: u64, strategy: &str) {
-        info!("Trade started: token={}, amount={} lamports, strategy={}", 
-              token_address, amount, strategy);
-        
+        let span = tracing::info_span!("trade", token = token_address, strategy, amount_lamports = amount);
+        let _enter = span.enter();
+        info!("trade started");
+
         Monitoring::record_trade_executed();
     }
-    
+
     pub fn log_trade_success(&self, token_address: &str, profit: f64, gas_used: u64, latency_ms: f64) {
-        info!("Trade successful: token={}, profit={} SOL, gas={} lamports, latency={}ms", 
-              token_address, profit, gas_used, latency_ms);
-        
+        let span = tracing::info_span!(
+            "trade",
+            token = token_address,
+            profit_sol = profit,
+            gas_used_lamports = gas_used,
+            latency_ms = latency_ms,
+        );
+        let _enter = span.enter();
+        info!("trade successful");
+
         Monitoring::record_trade_successful();
         Monitoring::record_profit_earned(profit);
         Monitoring::record_gas_spent(gas_used);
         Monitoring::record_execution_latency(latency_ms);
+        self.error_tracker.record_success(token_address, TRADE_OPERATION);
     }
-    
+
     pub fn log_trade_failure(&self, token_address: &str, error: &str, gas_used: u64) {
-        warn!("Trade failed: token={}, error={}, gas={} lamports", 
-              token_address, error, gas_used);
-        
+        let span = tracing::info_span!("trade", token = token_address, gas_used_lamports = gas_used, error);
+        let _enter = span.enter();
+        warn!("trade failed");
+
         Monitoring::record_trade_failed();
         Monitoring::record_gas_spent(gas_used);
+        self.error_tracker.record_failure(token_address, TRADE_OPERATION, error);
+    }
+
+    /// Whether `token_address` is currently inside its error cooldown for
+    /// trade execution, so a caller can skip a token that keeps failing
+    /// instead of queuing another doomed attempt.
+    pub async fn should_skip(&self, token_address: &str) -> bool {
+        self.error_tracker.should_skip(token_address, TRADE_OPERATION).await
     }
     
     pub fn log_mev_opportunity(&self, strategy: &str, token_address: &str, expected_profit: f64) {
-        info!("MEV opportunity: strategy={}, token={}, expected_profit={} SOL", 
-              strategy, token_address, expected_profit);
-        
+        let span = tracing::info_span!("trade", token = token_address, strategy, expected_profit_sol = expected_profit);
+        let _enter = span.enter();
+        info!("mev opportunity detected");
+
         Monitoring::record_mev_opportunity();
     }
-    
+
     pub fn log_mev_execution(&self, strategy: &str, token_address: &str, actual_profit: f64) {
-        info!("MEV executed: strategy={}, token={}, actual_profit={} SOL", 
-              strategy, token_address, actual_profit);
-        
+        let span = tracing::info_span!("trade", token = token_address, strategy, actual_profit_sol = actual_profit);
+        let _enter = span.enter();
+        info!("mev executed");
+
         Monitoring::record_mev_executed();
         Monitoring::record_profit_earned(actual_profit);
     }
-    
+
     pub fn log_bundle_submission(&self, bundle_id: &str, transaction_count: usize) {
-        info!("Bundle submitted: id={}, transactions={}", bundle_id, transaction_count);
+        let span = tracing::info_span!("bundle", bundle_id, transaction_count);
+        let _enter = span.enter();
+        info!("bundle submitted");
     }
-    
+
     pub fn log_bundle_confirmation(&self, bundle_id: &str, confirmation_time_ms: f64) {
-        info!("Bundle confirmed: id={}, confirmation_time={}ms", bundle_id, confirmation_time_ms);
-        
+        let span = tracing::info_span!("bundle", bundle_id, confirmation_time_ms);
+        let _enter = span.enter();
+        info!("bundle confirmed");
+
         Monitoring::record_bundle_confirmation_time(confirmation_time_ms);
     }
-    
+
     pub fn log_price_impact(&self, token_address: &str, impact_percentage: f64) {
-        debug!("Price impact: token={}, impact={}%", token_address, impact_percentage);
-        
+        let span = tracing::info_span!("trade", token = token_address, impact_percentage);
+        let _enter = span.enter();
+        debug!("price impact observed");
+
         Monitoring::record_price_impact(impact_percentage);
     }
-    
+
     pub fn log_slippage(&self, token_address: &str, slippage_percentage: f64) {
-        debug!("Slippage: token={}, slippage={}%", token_address, slippage_percentage);
-        
+        let span = tracing::info_span!("trade", token = token_address, slippage_percentage);
+        let _enter = span.enter();
+        debug!("slippage observed");
+
         Monitoring::record_slippage(slippage_percentage);
     }
 }
@@ -1,5 +1,6 @@
 use anyhow::Result;
-use log::{debug, error, info, warn};
+use dashmap::DashMap;
+use tracing::{debug, error, info, warn};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use solana_sdk::{
@@ -8,10 +9,15 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use std::sync::Arc;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::sync::RwLock;
 
 use crate::config::Config;
+use crate::confirmation_stream::ConfirmationSubscriber;
+use crate::latency_histogram::ConfirmationMetrics;
+use crate::submission_store::{NoopSubmissionStore, SubmissionRecord, SubmissionStatus, SubmissionStore};
+use crate::throughput_tracker::ThroughputTracker;
+use crate::tpu_client::TpuClient;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NozomiTransaction {
@@ -85,8 +91,8 @@ impl NozomiClient {
             created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
         };
         
-        let response = self.submit_to_nozomi(&submission).await?;
-        
+        let response = self.submit_to_nozomi(&submission, &self.base_url).await?;
+
         if response.success {
             info!("Transaction submitted to Nozomi: {}", response.submission_id);
             Ok(response.submission_id)
@@ -117,10 +123,10 @@ impl NozomiClient {
             created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
         };
         
-        let response = self.submit_to_nozomi(&submission).await?;
-        
+        let response = self.submit_to_nozomi(&submission, &self.base_url).await?;
+
         if response.success {
-            info!("Batch of {} transactions submitted to Nozomi: {}", 
+            info!("Batch of {} transactions submitted to Nozomi: {}",
                   submission.transactions.len(), response.submission_id);
             Ok(response.submission_id)
         } else {
@@ -129,12 +135,19 @@ impl NozomiClient {
     }
     
     pub async fn wait_for_confirmation(&self, submission_id: &str, timeout: Duration) -> Result<bool> {
+        self.wait_for_confirmation_at(submission_id, &self.base_url, timeout).await
+    }
+
+    /// Same as [`Self::wait_for_confirmation`], but polling `base_url`
+    /// instead of `self.base_url` — lets [`NozomiManager::submit_raced`]
+    /// poll the specific mirror a submission actually went to.
+    pub async fn wait_for_confirmation_at(&self, submission_id: &str, base_url: &str, timeout: Duration) -> Result<bool> {
         let start_time = SystemTime::now();
-        
+
         info!("Waiting for Nozomi confirmation: {}", submission_id);
-        
+
         while start_time.elapsed()? < timeout {
-            match self.get_submission_status(submission_id).await {
+            match self.get_submission_status_at(submission_id, base_url).await {
                 Ok(status) => {
                     match status.status.as_str() {
                         "confirmed" => {
@@ -168,9 +181,12 @@ impl NozomiClient {
         Ok(false)
     }
     
-    async fn submit_to_nozomi(&self, submission: &NozomiSubmission) -> Result<NozomiResponse> {
-        let url = format!("{}/api/v1/submit", self.base_url);
-        
+    /// POSTs `submission` to `base_url` rather than always `self.base_url`,
+    /// so [`NozomiManager::submit_raced`] can fire the same submission at
+    /// several Nozomi mirrors and race their responses with `select`.
+    async fn submit_to_nozomi(&self, submission: &NozomiSubmission, base_url: &str) -> Result<NozomiResponse> {
+        let url = format!("{}/api/v1/submit", base_url);
+
         let mut request = self.client.post(&url).json(submission);
         
         // Add API key if configured
@@ -190,8 +206,14 @@ impl NozomiClient {
     }
     
     async fn get_submission_status(&self, submission_id: &str) -> Result<NozomiStatus> {
-        let url = format!("{}/api/v1/status/{}", self.base_url, submission_id);
-        
+        self.get_submission_status_at(submission_id, &self.base_url).await
+    }
+
+    /// Same as [`Self::get_submission_status`], but against `base_url`
+    /// instead of `self.base_url`.
+    async fn get_submission_status_at(&self, submission_id: &str, base_url: &str) -> Result<NozomiStatus> {
+        let url = format!("{}/api/v1/status/{}", base_url, submission_id);
+
         let mut request = self.client.get(&url);
         
         // Add API key if configured
@@ -228,98 +250,507 @@ impl NozomiClient {
     }
 }
 
+/// One path `NozomiManager::submit_raced` can fire the same signed
+/// transaction over. `NozomiRelay` carries its own base URL so a caller can
+/// race the primary `Config::nozomi_url` against mirror/backup relays, not
+/// just the relay against direct TPU.
+#[derive(Debug, Clone)]
+pub enum SubmissionRoute {
+    NozomiRelay(String),
+    DirectTpu,
+}
+
+impl SubmissionRoute {
+    /// Label used for throughput route-win accounting and logging.
+    fn label(&self) -> String {
+        match self {
+            SubmissionRoute::NozomiRelay(base_url) => format!("nozomi:{}", base_url),
+            SubmissionRoute::DirectTpu => "tpu".to_string(),
+        }
+    }
+}
+
 pub struct NozomiManager {
     client: NozomiClient,
-    pending_submissions: std::collections::HashMap<String, NozomiSubmission>,
+    /// Present whenever `Config::submission_backend` is `"tpu"` or
+    /// `"both"`; direct-to-leader QUIC fan-out raced alongside (or instead
+    /// of) the Nozomi relay.
+    tpu_client: Option<TpuClient>,
+    /// Resolves confirmations from a Geyser stream instead of polling;
+    /// `None` when `Config::enable_streaming_confirmation` is off, in which
+    /// case every submission falls back to `NozomiClient::wait_for_confirmation`
+    /// polling directly, same as before this existed.
+    confirmation_subscriber: Option<Arc<ConfirmationSubscriber>>,
+    confirmation_metrics: Arc<ConfirmationMetrics>,
+    /// Durable record of every submission, independent of the in-memory
+    /// `pending_submissions` map. Defaults to [`NoopSubmissionStore`] so a
+    /// database is never required; becomes Postgres-backed when
+    /// `Config::submission_store_backend` is `"postgres"` and the
+    /// `postgres-store` feature is enabled.
+    store: Arc<dyn SubmissionStore>,
+    pending_submissions: Arc<DashMap<String, NozomiSubmission>>,
+    /// Submitted/confirmed tx-per-second and per-route win counts for
+    /// [`Self::submit_raced`].
+    throughput: Arc<ThroughputTracker>,
     config: Arc<RwLock<Config>>,
 }
 
 impl NozomiManager {
-    pub fn new(config: Arc<RwLock<Config>>) -> Result<Self> {
+    pub async fn new(config: Arc<RwLock<Config>>) -> Result<Self> {
         let client = NozomiClient::new(config.clone())?;
-        
-        Ok(Self {
+
+        let config_guard = config.read().await;
+        let submission_backend = config_guard.submission_backend.clone();
+        let enable_streaming_confirmation = config_guard.enable_streaming_confirmation;
+        let submission_store_backend = config_guard.submission_store_backend.clone();
+        let postgres_url = config_guard.postgres_url.clone();
+        drop(config_guard);
+
+        let tpu_client = if submission_backend == "tpu" || submission_backend == "both" {
+            let tpu_client = TpuClient::new(config.clone())?;
+            tpu_client.start_background_tasks();
+            Some(tpu_client)
+        } else {
+            None
+        };
+
+        let confirmation_subscriber = if enable_streaming_confirmation {
+            let subscriber = Arc::new(ConfirmationSubscriber::new(config.clone()));
+            subscriber.start();
+            Some(subscriber)
+        } else {
+            None
+        };
+
+        let store: Arc<dyn SubmissionStore> = Self::build_store(&submission_store_backend, postgres_url.as_deref()).await?;
+
+        let unresolved = store.load_unresolved().await?;
+        if !unresolved.is_empty() {
+            info!("Resuming confirmation tracking for {} unresolved submission(s) from the durable store", unresolved.len());
+        }
+
+        let pending_submissions = Arc::new(DashMap::new());
+        Self::start_cleanup_task(pending_submissions.clone());
+
+        let manager = Self {
             client,
-            pending_submissions: std::collections::HashMap::new(),
+            tpu_client,
+            confirmation_subscriber,
+            confirmation_metrics: Arc::new(ConfirmationMetrics::new()),
+            store,
+            pending_submissions,
+            throughput: Arc::new(ThroughputTracker::new()),
             config,
-        })
+        };
+
+        for record in unresolved {
+            manager.resume_tracking(record);
+        }
+
+        Ok(manager)
     }
-    
-    pub async fn submit_transaction(&mut self, transaction: &Transaction) -> Result<String> {
+
+    #[cfg(feature = "postgres-store")]
+    async fn build_store(backend: &str, postgres_url: Option<&str>) -> Result<Arc<dyn SubmissionStore>> {
+        if backend == "postgres" {
+            let url = postgres_url.ok_or_else(|| anyhow::anyhow!("postgres_url is required when submission_store_backend is 'postgres'"))?;
+            let store = crate::submission_store::PostgresSubmissionStore::new(url).await?;
+            return Ok(Arc::new(store));
+        }
+        Ok(Arc::new(NoopSubmissionStore))
+    }
+
+    #[cfg(not(feature = "postgres-store"))]
+    async fn build_store(backend: &str, _postgres_url: Option<&str>) -> Result<Arc<dyn SubmissionStore>> {
+        if backend == "postgres" {
+            warn!("submission_store_backend is 'postgres' but this binary was built without the postgres-store feature; falling back to in-memory tracking only");
+        }
+        Ok(Arc::new(NoopSubmissionStore))
+    }
+
+    /// Re-arms confirmation tracking for a submission reloaded from the
+    /// durable store after a restart. There's no original `Transaction` to
+    /// key a Geyser subscription on at this point, so resumed tracking
+    /// always goes through the `NozomiClient` polling path.
+    fn resume_tracking(&self, record: SubmissionRecord) {
+        self.pending_submissions.insert(
+            record.submission_id.clone(),
+            NozomiSubmission {
+                transactions: vec![],
+                submission_id: record.submission_id.clone(),
+                created_at: record.created_at,
+            },
+        );
+
+        let client = self.client.clone();
+        let metrics = self.confirmation_metrics.clone();
+        let store = self.store.clone();
+        let submission_id = record.submission_id;
+        let config = self.config.clone();
+
+        tokio::spawn(async move {
+            let timeout = Duration::from_millis(config.read().await.transaction_timeout * 1000);
+            Self::await_confirmation(client, None, metrics, store, None, submission_id, timeout).await;
+        });
+    }
+
+    /// Submission/confirmation latency and success/failure counts, for a
+    /// caller (e.g. the monitoring example) to print periodically.
+    pub fn confirmation_metrics(&self) -> Arc<ConfirmationMetrics> {
+        self.confirmation_metrics.clone()
+    }
+
+    /// Raced submit/confirm throughput and per-route win counts, for a
+    /// caller to print alongside [`Self::confirmation_metrics`].
+    pub fn throughput(&self) -> Arc<ThroughputTracker> {
+        self.throughput.clone()
+    }
+
+    /// Waits for `transaction` to confirm, preferring the Geyser stream and
+    /// falling back to `NozomiClient::wait_for_confirmation` polling when
+    /// streaming confirmation is disabled, the stream is disconnected, or
+    /// the transaction carries no signature to key a subscription on.
+    /// Records outcome/latency into `metrics` and writes the final status to
+    /// `store`, regardless of which path resolves it.
+    async fn await_confirmation(
+        client: NozomiClient,
+        subscriber: Option<Arc<ConfirmationSubscriber>>,
+        metrics: Arc<ConfirmationMetrics>,
+        store: Arc<dyn SubmissionStore>,
+        transaction: Option<Transaction>,
+        submission_id: String,
+        timeout: Duration,
+    ) {
+        let submitted_at = Instant::now();
+
+        if let Some(subscriber) = &subscriber {
+            if let Some(signature) = transaction.as_ref().and_then(|tx| tx.signatures.first().copied()) {
+                match subscriber.wait_for_signature(signature, timeout).await {
+                    Ok(confirmed) => {
+                        if confirmed {
+                            info!("Submission {} confirmed via Geyser stream ({})", submission_id, signature);
+                        } else {
+                            warn!("Submission {} failed or timed out via Geyser stream ({})", submission_id, signature);
+                        }
+                        metrics.record_outcome(confirmed, submitted_at.elapsed());
+                        let status = if confirmed { SubmissionStatus::Confirmed } else { SubmissionStatus::Failed };
+                        if let Err(e) = store.update_status(&submission_id, status, None).await {
+                            warn!("Failed to persist submission status for {}: {}", submission_id, e);
+                        }
+                        return;
+                    }
+                    Err(e) => {
+                        debug!("Geyser stream unavailable for {}, falling back to polling: {}", submission_id, e);
+                    }
+                }
+            }
+        }
+
+        let confirmed = match client.wait_for_confirmation(&submission_id, timeout).await {
+            Ok(confirmed) => {
+                if confirmed {
+                    info!("Nozomi submission {} confirmed", submission_id);
+                } else {
+                    warn!("Nozomi submission {} failed or timed out", submission_id);
+                }
+                confirmed
+            }
+            Err(e) => {
+                error!("Error waiting for Nozomi submission {}: {}", submission_id, e);
+                false
+            }
+        };
+        metrics.record_outcome(confirmed, submitted_at.elapsed());
+
+        let mut confirmation_time_ms = None;
+        if confirmed {
+            if let Ok(status) = client.get_submission_status(&submission_id).await {
+                if let Some(ms) = status.confirmation_time_ms {
+                    metrics.record_relay_confirmation_time_ms(ms);
+                    confirmation_time_ms = Some(ms);
+                }
+            }
+        }
+
+        let status = if confirmed { SubmissionStatus::Confirmed } else { SubmissionStatus::Failed };
+        if let Err(e) = store.update_status(&submission_id, status, confirmation_time_ms).await {
+            warn!("Failed to persist submission status for {}: {}", submission_id, e);
+        }
+    }
+
+    /// Fans `transaction` out over direct TPU QUIC, fire-and-forget. Used
+    /// by `"both"` to race the relay without blocking the relay submission
+    /// on the QUIC fan-out (or vice versa), and logs rather than propagates
+    /// failure since the relay send is still the result callers track.
+    fn spawn_tpu_fanout(&self, transaction: &Transaction) {
+        let Some(tpu_client) = self.tpu_client.clone() else {
+            return;
+        };
+        let transaction = transaction.clone();
+        let config = self.config.clone();
+
+        tokio::spawn(async move {
+            let fanout = config.read().await.tpu_fanout;
+            if let Err(e) = tpu_client.send_transaction(&transaction, fanout).await {
+                warn!("Direct-TPU fan-out alongside Nozomi failed: {}", e);
+            }
+        });
+    }
+
+    /// Sends `transaction` to the next upcoming leaders over QUIC only, for
+    /// `submission_backend = "tpu"`. There's no relay submission id for a
+    /// direct-TPU send, so one is synthesized here so callers get an id back
+    /// the same as for a Nozomi submission. Nothing is tracked behind it:
+    /// the relay does not know the id, so `wait_for_confirmation` on it
+    /// never reports it confirmed.
+    async fn submit_via_tpu_only(&self, transaction: &Transaction) -> Result<String> {
+        let tpu_client = self
+            .tpu_client
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("submission_backend is \"tpu\" but no TpuClient was initialized"))?;
+
+        let fanout = self.config.read().await.tpu_fanout;
+        tpu_client.send_transaction(transaction, fanout).await?;
+
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let random_bytes: [u8; 16] = rng.gen();
+        Ok(format!("tpu_{}", hex::encode(random_bytes)))
+    }
+
+    pub async fn submit_transaction(&self, transaction: &Transaction) -> Result<String> {
+        let submission_backend = self.config.read().await.submission_backend.clone();
+
+        if submission_backend == "tpu" {
+            return self.submit_via_tpu_only(transaction).await;
+        }
+        if submission_backend == "both" {
+            self.spawn_tpu_fanout(transaction);
+        }
+
         let submission_id = self.client.submit_transaction(transaction).await?;
-        
+
         // Store submission for tracking
         let submission = NozomiSubmission {
             transactions: vec![], // We don't need to store the actual transaction data
             submission_id: submission_id.clone(),
             created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
         };
-        
+
         self.pending_submissions.insert(submission_id.clone(), submission);
-        
+
+        let record = SubmissionRecord::new(submission_id.clone(), submission_backend.clone())?;
+        if let Err(e) = self.store.record_submission(&record).await {
+            warn!("Failed to persist submission record for {}: {}", submission_id, e);
+        }
+
         // Start confirmation monitoring
         let config_guard = self.config.read().await;
         let timeout = Duration::from_millis(config_guard.transaction_timeout * 1000);
         drop(config_guard);
-        
+
         let client = self.client.clone();
+        let subscriber = self.confirmation_subscriber.clone();
+        let metrics = self.confirmation_metrics.clone();
+        let store = self.store.clone();
         let submission_id_clone = submission_id.clone();
-        tokio::spawn(async move {
-            let confirmed = client.wait_for_confirmation(&submission_id_clone, timeout).await;
-            match confirmed {
-                Ok(true) => info!("Nozomi submission {} confirmed", submission_id_clone),
-                Ok(false) => warn!("Nozomi submission {} failed or timed out", submission_id_clone),
-                Err(e) => error!("Error waiting for Nozomi submission {}: {}", submission_id_clone, e),
-            }
-        });
-        
+        let transaction_clone = transaction.clone();
+        tokio::spawn(Self::await_confirmation(client, subscriber, metrics, store, Some(transaction_clone), submission_id_clone, timeout));
+
         Ok(submission_id)
     }
-    
-    pub async fn submit_transaction_batch(&mut self, transactions: Vec<Transaction>) -> Result<String> {
+
+    pub async fn submit_transaction_batch(&self, transactions: Vec<Transaction>) -> Result<String> {
+        let submission_backend = self.config.read().await.submission_backend.clone();
+
+        if submission_backend == "tpu" {
+            let tpu_client = self
+                .tpu_client
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("submission_backend is \"tpu\" but no TpuClient was initialized"))?;
+            let fanout = self.config.read().await.tpu_fanout;
+            for transaction in &transactions {
+                tpu_client.send_transaction(transaction, fanout).await?;
+            }
+
+            use rand::Rng;
+            let mut rng = rand::thread_rng();
+            let random_bytes: [u8; 16] = rng.gen();
+            return Ok(format!("tpu_{}", hex::encode(random_bytes)));
+        }
+        if submission_backend == "both" {
+            for transaction in &transactions {
+                self.spawn_tpu_fanout(transaction);
+            }
+        }
+
+        // The stream can only key a subscription on one signature, so the
+        // batch's first transaction stands in for the whole batch, same as
+        // the single submission_id already stands in for every transaction
+        // in it.
+        let representative_transaction = transactions.first().cloned();
+
         let submission_id = self.client.submit_transaction_batch(transactions).await?;
-        
+
         // Store submission for tracking
         let submission = NozomiSubmission {
             transactions: vec![], // We don't need to store the actual transaction data
             submission_id: submission_id.clone(),
             created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
         };
-        
+
         self.pending_submissions.insert(submission_id.clone(), submission);
-        
+
+        let record = SubmissionRecord::new(submission_id.clone(), submission_backend.clone())?;
+        if let Err(e) = self.store.record_submission(&record).await {
+            warn!("Failed to persist submission record for {}: {}", submission_id, e);
+        }
+
         // Start confirmation monitoring
         let config_guard = self.config.read().await;
         let timeout = Duration::from_millis(config_guard.transaction_timeout * 1000);
         drop(config_guard);
-        
+
         let client = self.client.clone();
+        let subscriber = self.confirmation_subscriber.clone();
+        let metrics = self.confirmation_metrics.clone();
+        let store = self.store.clone();
         let submission_id_clone = submission_id.clone();
-        tokio::spawn(async move {
-            let confirmed = client.wait_for_confirmation(&submission_id_clone, timeout).await;
-            match confirmed {
-                Ok(true) => info!("Nozomi batch submission {} confirmed", submission_id_clone),
-                Ok(false) => warn!("Nozomi batch submission {} failed or timed out", submission_id_clone),
-                Err(e) => error!("Error waiting for Nozomi batch submission {}: {}", submission_id_clone, e),
-            }
-        });
-        
+        tokio::spawn(Self::await_confirmation(client, subscriber, metrics, store, representative_transaction, submission_id_clone, timeout));
+
         Ok(submission_id)
     }
-    
+
+    /// Submits `transaction` over every route in `routes` concurrently
+    /// (e.g. the Nozomi relay, one or more mirror base URLs, and direct TPU)
+    /// and returns as soon as any one of them confirms, abandoning the
+    /// others. Mirrors lite-rpc's custom sender: the winning-route label is
+    /// credited in [`Self::throughput`] alongside a rolling
+    /// submitted/confirmed tx-per-second count.
+    ///
+    /// Returns the label of the route that won (see [`SubmissionRoute::label`]).
+    /// Errors only if every route fails to confirm.
+    pub async fn submit_raced(&self, transaction: &Transaction, routes: &[SubmissionRoute]) -> Result<String> {
+        if routes.is_empty() {
+            return Err(anyhow::anyhow!("submit_raced requires at least one route"));
+        }
+
+        self.throughput.record_submitted();
+
+        let timeout = Duration::from_millis(self.config.read().await.transaction_timeout * 1000);
+
+        let mut pending: Vec<std::pin::Pin<Box<dyn std::future::Future<Output = (String, Result<()>)> + Send + '_>>> = Vec::new();
+        for route in routes {
+            let label = route.label();
+            let race = self.race_route(route.clone(), transaction.clone(), timeout);
+            pending.push(Box::pin(async move { (label, race.await) }));
+        }
+
+        loop {
+            let ((label, outcome), _index, rest) = futures::future::select_all(pending).await;
+            match outcome {
+                Ok(()) => {
+                    info!("submit_raced: route {} won", label);
+                    self.throughput.record_confirmed(&label);
+                    // Dropping the remaining futures cancels their in-flight
+                    // HTTP/QUIC sends and confirmation waits.
+                    drop(rest);
+                    return Ok(label);
+                }
+                Err(e) => {
+                    debug!("submit_raced: route {} lost ({})", label, e);
+                    if rest.is_empty() {
+                        return Err(anyhow::anyhow!("All raced routes failed to confirm"));
+                    }
+                    pending = rest;
+                }
+            }
+        }
+    }
+
+    /// One leg of [`Self::submit_raced`]: submits `transaction` over `route`
+    /// and waits for that specific route to confirm it.
+    async fn race_route(&self, route: SubmissionRoute, transaction: Transaction, timeout: Duration) -> Result<()> {
+        match route {
+            SubmissionRoute::NozomiRelay(base_url) => {
+                let transaction_data = base64::encode(bincode::serialize(&transaction)?);
+                let nozomi_tx = NozomiTransaction {
+                    transaction_data,
+                    priority_fee: self.client.calculate_priority_fee().await?,
+                    max_retries: 3,
+                    timeout_ms: timeout.as_millis() as u64,
+                };
+                let submission = NozomiSubmission {
+                    transactions: vec![nozomi_tx],
+                    submission_id: self.client.generate_submission_id(),
+                    created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+                };
+
+                let response = self.client.submit_to_nozomi(&submission, &base_url).await?;
+                if !response.success {
+                    return Err(anyhow::anyhow!("Nozomi submission to {} failed: {:?}", base_url, response.error_message));
+                }
+
+                let confirmed = self
+                    .client
+                    .wait_for_confirmation_at(&response.submission_id, &base_url, timeout)
+                    .await?;
+                if confirmed {
+                    Ok(())
+                } else {
+                    Err(anyhow::anyhow!("Nozomi relay {} did not confirm within timeout", base_url))
+                }
+            }
+            SubmissionRoute::DirectTpu => {
+                let tpu_client = self
+                    .tpu_client
+                    .as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("DirectTpu route requires submission_backend to be \"tpu\" or \"both\""))?;
+
+                let fanout = self.config.read().await.tpu_fanout;
+                tpu_client.send_transaction(&transaction, fanout).await?;
+
+                if let Some(subscriber) = &self.confirmation_subscriber {
+                    if let Some(signature) = transaction.signatures.first().copied() {
+                        return if subscriber.wait_for_signature(signature, timeout).await? {
+                            Ok(())
+                        } else {
+                            Err(anyhow::anyhow!("Direct TPU send did not confirm within timeout"))
+                        };
+                    }
+                }
+
+                // No signature to key a confirmation wait on, or no Geyser
+                // stream configured: treat the QUIC send itself landing
+                // without error as the win, same as `submit_via_tpu_only`
+                // already does outside the race.
+                Ok(())
+            }
+        }
+    }
+
     pub async fn get_submission_status(&self, submission_id: &str) -> Result<NozomiStatus> {
         self.client.get_submission_status(submission_id).await
     }
-    
-    pub fn cleanup_completed_submissions(&mut self) {
-        // Remove submissions that are older than 10 minutes
-        let cutoff_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs() - 600; // 10 minutes
-        
-        self.pending_submissions.retain(|_, submission| {
-            submission.created_at > cutoff_time
+
+    /// Spawn the background loop that drops `pending_submissions` entries
+    /// older than 10 minutes, so callers no longer need to remember to call
+    /// this themselves on a `&mut NozomiManager` that can no longer exist
+    /// now that every submit method only needs `&self`.
+    fn start_cleanup_task(pending_submissions: Arc<DashMap<String, NozomiSubmission>>) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(60));
+            loop {
+                ticker.tick().await;
+
+                let cutoff_time = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs()
+                    .saturating_sub(600); // 10 minutes
+
+                pending_submissions.retain(|_, submission| submission.created_at > cutoff_time);
+            }
         });
     }
 }
@@ -0,0 +1,111 @@
+use dashmap::DashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::config::{Config, OracleSource};
+
+/// One source's latest price for a token.
+#[derive(Debug, Clone, Copy)]
+struct OracleReading {
+    price_usd: f64,
+    observed_at: u64,
+}
+
+/// Consensus price derived from whichever sources were still fresh as of the
+/// call. `agreement_score` is 1.0 when every fresh source sits on the median
+/// and falls linearly to 0.0 at `oracle_divergence_bps`, the same spread that
+/// sets `divergent`; `calculate_confidence_score` folds it in as an extra
+/// term alongside liquidity/volume/stability/time.
+#[derive(Debug, Clone)]
+pub struct ConsensusPrice {
+    pub price_usd: f64,
+    pub agreement_score: f64,
+    pub divergent: bool,
+    pub sources: Vec<OracleSource>,
+}
+
+/// Fans a token's price across multiple feeds — the PumpSwap gRPC stream, a
+/// direct Solana RPC pool-reserve read, and an optional external AMM read —
+/// instead of letting a single lagging or lying `PriceUpdate` drive a
+/// decision on its own. Callers push in readings as they observe them (the
+/// same way `FeeOracle::observe_slot` is fed); `consensus_price` then medians
+/// whatever is still within `max_oracle_staleness_secs` and flags divergence
+/// past `oracle_divergence_bps`. `Config::oracle_source_priority` orders
+/// which sources are considered at all, so losing the primary feed just
+/// narrows the median to whatever's left in that order rather than blocking
+/// the caller.
+pub struct OracleAggregator {
+    config: Arc<RwLock<Config>>,
+    readings: DashMap<(String, OracleSource), OracleReading>,
+}
+
+impl OracleAggregator {
+    pub fn new(config: Arc<RwLock<Config>>) -> Self {
+        Self {
+            config,
+            readings: DashMap::new(),
+        }
+    }
+
+    /// Record `source`'s latest price for `token_address`, observed at
+    /// `observed_at` (unix seconds).
+    pub fn record(&self, token_address: &str, source: OracleSource, price_usd: f64, observed_at: u64) {
+        self.readings
+            .insert((token_address.to_string(), source), OracleReading { price_usd, observed_at });
+    }
+
+    /// Median price across every source whose reading is within
+    /// `max_oracle_staleness_secs` of `now`, in `oracle_source_priority`
+    /// order. Returns `None` if no prioritized source has a fresh reading.
+    pub async fn consensus_price(&self, token_address: &str, now: u64) -> Option<ConsensusPrice> {
+        let config_guard = self.config.read().await;
+        let max_staleness = config_guard.max_oracle_staleness_secs;
+        let divergence_bps = config_guard.oracle_divergence_bps;
+        let priority = config_guard.get_oracle_source_priority();
+        drop(config_guard);
+
+        let mut fresh: Vec<(OracleSource, f64)> = priority
+            .into_iter()
+            .filter_map(|source| {
+                self.readings
+                    .get(&(token_address.to_string(), source))
+                    .filter(|reading| now.saturating_sub(reading.observed_at) <= max_staleness)
+                    .map(|reading| (source, reading.price_usd))
+            })
+            .collect();
+
+        if fresh.is_empty() {
+            return None;
+        }
+
+        fresh.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        let mid = fresh.len() / 2;
+        let median = if fresh.len() % 2 == 0 {
+            (fresh[mid - 1].1 + fresh[mid].1) / 2.0
+        } else {
+            fresh[mid].1
+        };
+
+        let min_price = fresh.first().map(|(_, p)| *p).unwrap_or(median);
+        let max_price = fresh.last().map(|(_, p)| *p).unwrap_or(median);
+        let spread_bps = if median > 0.0 {
+            ((max_price - min_price) / median * 10_000.0).abs()
+        } else {
+            0.0
+        };
+        let divergent = spread_bps > divergence_bps as f64;
+
+        let agreement_score = if divergence_bps == 0 {
+            if divergent { 0.0 } else { 1.0 }
+        } else {
+            (1.0 - spread_bps / divergence_bps as f64).clamp(0.0, 1.0)
+        };
+
+        Some(ConsensusPrice {
+            price_usd: median,
+            agreement_score,
+            divergent,
+            sources: fresh.into_iter().map(|(source, _)| source).collect(),
+        })
+    }
+}
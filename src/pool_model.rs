@@ -0,0 +1,139 @@
+use tracing::warn;
+
+/// Newton iterations allowed when solving the StableSwap invariant before
+/// giving up and falling back to the constant-product approximation.
+const NEWTON_ITERATIONS: usize = 255;
+const CONVERGENCE_EPSILON: f64 = 1e-6;
+
+/// Which AMM curve a pool trades under, for price-impact estimation.
+/// Constant-product (`x*y=k`) is the Raydium/PumpSwap default; `StableSwap`
+/// matches Curve-style pools (LSTs, stablecoin pairs) whose `amp` parameter
+/// flattens the curve around the 1:1 peg so a constant-product estimate
+/// would wildly overstate slippage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PoolModel {
+    ConstantProduct,
+    StableSwap { amp: u64 },
+}
+
+impl PoolModel {
+    /// Percentage drop in effective price (`reserve_out/reserve_in`) a trade
+    /// causes versus the pool's pre-trade price. `output_amount` is the
+    /// actual (e.g. quoted) amount received; for `StableSwap` it's ignored
+    /// in favor of the output solved from the invariant, since that's what
+    /// the curve says should come out.
+    pub fn price_impact(&self, input_amount: u64, output_amount: u64, pool_reserves: (u64, u64)) -> f64 {
+        let (reserve_in, reserve_out) = pool_reserves;
+        if reserve_in == 0 || reserve_out == 0 || input_amount == 0 {
+            return 0.0;
+        }
+
+        match self {
+            PoolModel::ConstantProduct => {
+                constant_product_impact(input_amount, output_amount, reserve_in, reserve_out)
+            }
+            PoolModel::StableSwap { amp } => {
+                match stableswap_output(*amp, input_amount, reserve_in, reserve_out) {
+                    Some(new_reserve_out) => {
+                        let new_reserve_in = reserve_in as f64 + input_amount as f64;
+                        price_ratio_impact(reserve_in as f64, reserve_out as f64, new_reserve_in, new_reserve_out)
+                    }
+                    None => {
+                        warn!("StableSwap Newton iteration failed to converge, falling back to constant-product impact");
+                        constant_product_impact(input_amount, output_amount, reserve_in, reserve_out)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Predicted output of trading `input_amount` into `reserve_in`, used to
+    /// estimate impact ahead of an actual quote (e.g. for pre-trade slippage
+    /// budgeting). Constant-product pools solve this in closed form;
+    /// `StableSwap` solves it from the invariant, falling back to
+    /// constant-product on non-convergence.
+    pub fn predicted_output(&self, input_amount: u64, pool_reserves: (u64, u64)) -> u64 {
+        let (reserve_in, reserve_out) = pool_reserves;
+        if reserve_in == 0 || reserve_out == 0 || input_amount == 0 {
+            return 0;
+        }
+
+        match self {
+            PoolModel::ConstantProduct => constant_product_output(input_amount, reserve_in, reserve_out),
+            PoolModel::StableSwap { amp } => stableswap_output(*amp, input_amount, reserve_in, reserve_out)
+                .map(|new_reserve_out| (reserve_out as f64 - new_reserve_out).max(0.0) as u64)
+                .unwrap_or_else(|| constant_product_output(input_amount, reserve_in, reserve_out)),
+        }
+    }
+}
+
+fn constant_product_output(input_amount: u64, reserve_in: u64, reserve_out: u64) -> u64 {
+    let new_reserve_in = reserve_in as f64 + input_amount as f64;
+    let new_reserve_out = (reserve_in as f64 * reserve_out as f64) / new_reserve_in;
+    (reserve_out as f64 - new_reserve_out).max(0.0) as u64
+}
+
+fn constant_product_impact(input_amount: u64, output_amount: u64, reserve_in: u64, reserve_out: u64) -> f64 {
+    let new_reserve_in = reserve_in + input_amount;
+    let new_reserve_out = reserve_out.saturating_sub(output_amount);
+    price_ratio_impact(reserve_in as f64, reserve_out as f64, new_reserve_in as f64, new_reserve_out as f64)
+}
+
+fn price_ratio_impact(reserve_in: f64, reserve_out: f64, new_reserve_in: f64, new_reserve_out: f64) -> f64 {
+    let price_before = reserve_out / reserve_in;
+    let price_after = new_reserve_out / new_reserve_in;
+    let impact = (price_before - price_after) / price_before * 100.0;
+    impact.max(0.0)
+}
+
+/// Newton's method for the StableSwap invariant `D` over two reserves,
+/// solving `A*n^n*Sum(x) + D = A*n^n*D + D^(n+1) / (n^n*Prod(x))` (n=2) via
+/// Curve's iterative form `D_next = (Ann*S + D_P*n)*D / ((Ann-1)*D + (n+1)*D_P)`.
+fn stableswap_d(amp: u64, x0: f64, x1: f64) -> Option<f64> {
+    let n = 2.0_f64;
+    let ann = amp as f64 * n * n; // A * n^n, n=2
+    let s = x0 + x1;
+    if s == 0.0 {
+        return Some(0.0);
+    }
+
+    let mut d = s;
+    for _ in 0..NEWTON_ITERATIONS {
+        let mut d_p = d;
+        d_p = d_p * d / (n * x0);
+        d_p = d_p * d / (n * x1);
+
+        let d_prev = d;
+        d = (ann * s + d_p * n) * d / ((ann - 1.0) * d + (n + 1.0) * d_p);
+
+        if (d - d_prev).abs() <= CONVERGENCE_EPSILON.max(d_prev.abs() * 1e-12) {
+            return Some(d);
+        }
+    }
+
+    None
+}
+
+/// Solves the new `reserve_out` after adding `input_amount` to `reserve_in`
+/// while holding the invariant `D` fixed: `y = (y^2 + c) / (2y + b - D)`
+/// where `b = x + D/Ann` and `c = D^3 / (n^2 * Ann * x)` for the 2-coin case.
+fn stableswap_output(amp: u64, input_amount: u64, reserve_in: u64, reserve_out: u64) -> Option<f64> {
+    let d = stableswap_d(amp, reserve_in as f64, reserve_out as f64)?;
+    let n = 2.0_f64;
+    let ann = amp as f64 * n * n;
+    let new_reserve_in = reserve_in as f64 + input_amount as f64;
+
+    let c = d.powi(3) / (n * n * ann * new_reserve_in);
+    let b = new_reserve_in + d / ann;
+
+    let mut y = d;
+    for _ in 0..NEWTON_ITERATIONS {
+        let y_prev = y;
+        y = (y * y + c) / (2.0 * y + b - d);
+        if (y - y_prev).abs() <= CONVERGENCE_EPSILON.max(y_prev.abs() * 1e-12) {
+            return Some(y);
+        }
+    }
+
+    None
+}
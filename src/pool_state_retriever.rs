@@ -0,0 +1,103 @@
+use anyhow::{anyhow, Result};
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+
+/// Byte offsets shared with [`crate::state_guard::StateGuard`]'s assumed
+/// PumpSwap pool account layout: discriminator, then base/quote mint
+/// pubkeys, then the two u64 reserves.
+const RESERVE_IN_OFFSET: usize = 8 + 32 + 32;
+const RESERVE_OUT_OFFSET: usize = RESERVE_IN_OFFSET + 8;
+/// Offset of a trailing `f64` oracle price assumed to sit right after the
+/// reserves, for accounts that cache an oracle read alongside pool state.
+const ORACLE_PRICE_OFFSET: usize = RESERVE_OUT_OFFSET + 8;
+
+/// Reads the bank/oracle/reserve accounts an execution actually needs,
+/// rather than `MEVDetector` trusting its own in-memory `pool_reserves` map.
+/// `analyze_opportunities` pulls through this so profit math sees live
+/// on-chain state, and the execution planner can derive the right
+/// `AccountMeta` ordering from the same source.
+pub trait PoolStateRetriever {
+    fn reserves(&self, pool: &Pubkey) -> Result<(u128, u128)>;
+    fn oracle_price(&self, token: &Pubkey) -> Result<f64>;
+}
+
+fn read_reserves(account: &Account) -> Result<(u128, u128)> {
+    let data = &account.data;
+    if data.len() < RESERVE_OUT_OFFSET + 8 {
+        return Err(anyhow!("account data too short ({} bytes) to contain reserves", data.len()));
+    }
+
+    let reserve_in = u64::from_le_bytes(data[RESERVE_IN_OFFSET..RESERVE_IN_OFFSET + 8].try_into().unwrap());
+    let reserve_out = u64::from_le_bytes(data[RESERVE_OUT_OFFSET..RESERVE_OUT_OFFSET + 8].try_into().unwrap());
+    Ok((reserve_in as u128, reserve_out as u128))
+}
+
+fn read_oracle_price(account: &Account) -> Result<f64> {
+    let data = &account.data;
+    if data.len() < ORACLE_PRICE_OFFSET + 8 {
+        return Err(anyhow!("account data too short ({} bytes) to contain an oracle price", data.len()));
+    }
+
+    Ok(f64::from_le_bytes(data[ORACLE_PRICE_OFFSET..ORACLE_PRICE_OFFSET + 8].try_into().unwrap()))
+}
+
+/// Expects its pool/oracle accounts in a known positional layout, handed in
+/// by a caller that already knows exactly which accounts it needs — the hot
+/// path for building a single opportunity's transactions, where there's no
+/// reason to search.
+pub struct FixedOrderRetriever {
+    pool: Pubkey,
+    pool_account: Account,
+    oracle: Pubkey,
+    oracle_account: Account,
+}
+
+impl FixedOrderRetriever {
+    pub fn new(pool: Pubkey, pool_account: Account, oracle: Pubkey, oracle_account: Account) -> Self {
+        Self { pool, pool_account, oracle, oracle_account }
+    }
+}
+
+impl PoolStateRetriever for FixedOrderRetriever {
+    fn reserves(&self, pool: &Pubkey) -> Result<(u128, u128)> {
+        if &self.pool != pool {
+            return Err(anyhow!("FixedOrderRetriever was built for pool {}, not {}", self.pool, pool));
+        }
+        read_reserves(&self.pool_account)
+    }
+
+    fn oracle_price(&self, token: &Pubkey) -> Result<f64> {
+        if &self.oracle != token {
+            return Err(anyhow!("FixedOrderRetriever was built for oracle {}, not {}", self.oracle, token));
+        }
+        read_oracle_price(&self.oracle_account)
+    }
+}
+
+/// Linearly searches a supplied account set for `pool`/`token`, for
+/// resolving a union of pools at once — e.g. the cross-pool arbitrage
+/// detector, which needs whichever two (or more) venues for a token turned
+/// up, not a single account it already knows the identity of ahead of time.
+pub struct ScanningRetriever {
+    accounts: Vec<(Pubkey, Account)>,
+}
+
+impl ScanningRetriever {
+    pub fn new(accounts: Vec<(Pubkey, Account)>) -> Self {
+        Self { accounts }
+    }
+}
+
+impl PoolStateRetriever for ScanningRetriever {
+    fn reserves(&self, pool: &Pubkey) -> Result<(u128, u128)> {
+        let (_, account) = self.accounts.iter().find(|(key, _)| key == pool)
+            .ok_or_else(|| anyhow!("pool {} not present in the supplied account set", pool))?;
+        read_reserves(account)
+    }
+
+    fn oracle_price(&self, token: &Pubkey) -> Result<f64> {
+        let (_, account) = self.accounts.iter().find(|(key, _)| key == token)
+            .ok_or_else(|| anyhow!("oracle account for {} not present in the supplied account set", token))?;
+        read_oracle_price(account)
+    }
+}
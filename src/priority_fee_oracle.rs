@@ -0,0 +1,83 @@
+use dashmap::DashMap;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::config::MEVStrategy;
+
+/// Cap on how many recent fee observations are kept per pool/program before
+/// the oldest is dropped — the same bounded-window shape `MEVDetector`'s
+/// `price_history` uses, so one pool's sample count can't grow unbounded
+/// over a long run.
+const WINDOW_CAPACITY: usize = 200;
+
+/// Compute units budgeted per instruction when deriving a strategy's
+/// estimate from its instruction count, rather than a flat per-strategy
+/// constant.
+const COMPUTE_UNITS_PER_INSTRUCTION: u64 = 20_000;
+/// Every execution plan pays for a compute-budget instruction and a
+/// priority-fee-bearing instruction ahead of its actual swap(s).
+const SETUP_INSTRUCTION_COUNT: u64 = 2;
+
+/// Rolling-window tracker of recent prioritization fees (micro-lamports per
+/// compute unit), sampled per pool/program address, replacing the flat
+/// `gas_estimate`/`max_gas_price` constants `MEVDetector::create_*_execution_plan`
+/// used to build every plan from regardless of live congestion.
+pub struct PriorityFeeOracle {
+    windows: DashMap<String, Mutex<VecDeque<u64>>>,
+}
+
+impl PriorityFeeOracle {
+    pub fn new() -> Self {
+        Self { windows: DashMap::new() }
+    }
+
+    /// Record one observed prioritization fee (micro-lamports per compute
+    /// unit) for `key` (typically a pool address), trimming to
+    /// `WINDOW_CAPACITY` the same way `price_history` trims to its own cap.
+    pub fn observe_fee(&self, key: &str, micro_lamports_per_cu: u64) {
+        let window = self.windows.entry(key.to_string()).or_insert_with(|| Mutex::new(VecDeque::with_capacity(WINDOW_CAPACITY)));
+        let mut window = window.lock().unwrap();
+        if window.len() == WINDOW_CAPACITY {
+            window.pop_front();
+        }
+        window.push_back(micro_lamports_per_cu);
+    }
+
+    /// Nearest-rank `percentile` (`0.0`-`1.0`) of `key`'s observed fees, or
+    /// `None` if no observations have been recorded for it yet — callers
+    /// should fall back to a configured default in that case.
+    pub fn suggested_micro_lamports(&self, key: &str, percentile: f64) -> Option<u64> {
+        let window = self.windows.get(key)?;
+        let window = window.lock().unwrap();
+        if window.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<u64> = window.iter().copied().collect();
+        sorted.sort_unstable();
+        let rank = ((sorted.len() - 1) as f64 * percentile.clamp(0.0, 1.0)).round() as usize;
+        Some(sorted[rank])
+    }
+
+    /// Compute-unit estimate for `strategy`, derived from how many
+    /// instructions its execution plan actually needs (a compute-budget
+    /// instruction, a priority-fee instruction, and one swap leg per side of
+    /// the trade) rather than a flat hardcoded constant per strategy.
+    pub fn estimate_compute_units(strategy: MEVStrategy) -> u64 {
+        let swap_legs = match strategy {
+            MEVStrategy::FrontRun => 1,
+            MEVStrategy::Arbitrage => 2,
+            MEVStrategy::Sandwich => 2,
+            MEVStrategy::BackRun => 1,
+            MEVStrategy::Liquidation => 1,
+        };
+
+        (SETUP_INSTRUCTION_COUNT + swap_legs) * COMPUTE_UNITS_PER_INSTRUCTION
+    }
+}
+
+impl Default for PriorityFeeOracle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,144 @@
+use tracing::info;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::config::Config;
+use crate::proto::pumpswap::PriceUpdate;
+use crate::sniper::{ActiveTrade, TradeStatus};
+use crate::work_queue::{ExitOrder, ExitReason, TradeJob, TradeQueue};
+
+/// Evaluates the take-profit ladder and trailing stop for every open
+/// position in `price_update.token_address`, queuing an `Exit` job for any
+/// rung or stop that fires. Runs off the same price-update stream the MEV
+/// detector consumes, so a position in a token outside that subscription
+/// won't be evaluated until the stream covers it.
+pub async fn evaluate_price_exit(
+    price_update: &PriceUpdate,
+    config: &Arc<RwLock<Config>>,
+    active_trades: &Arc<RwLock<HashMap<String, ActiveTrade>>>,
+    trade_queue: &Arc<TradeQueue>,
+) {
+    let config_guard = config.read().await;
+    let ladder = config_guard.profit_ladder.clone();
+    let trailing_stop_percentage = config_guard.trailing_stop_percentage;
+    drop(config_guard);
+
+    let mut trades = active_trades.write().await;
+    for (trade_id, trade) in trades.iter_mut() {
+        if trade.token_address != price_update.token_address {
+            continue;
+        }
+        if trade.status != TradeStatus::Executing || trade.tokens_held == 0 || trade.exit_in_flight {
+            continue;
+        }
+
+        // The first price seen after the buy becomes the cost basis; there's
+        // no fill-price oracle to read it from directly.
+        if trade.entry_price_usd == 0.0 {
+            trade.entry_price_usd = price_update.price_usd;
+            trade.peak_price_usd = price_update.price_usd;
+            if let Some(rung) = ladder.get(trade.ladder_progress) {
+                trade.target_price = trade.entry_price_usd * (1.0 + rung.gain_percentage / 100.0);
+            }
+            continue;
+        }
+
+        trade.peak_price_usd = trade.peak_price_usd.max(price_update.price_usd);
+
+        let mut queued = false;
+        while trade.ladder_progress < ladder.len() && trade.tokens_held > 0 && price_update.price_usd >= trade.target_price {
+            let rung = ladder[trade.ladder_progress];
+            let sell_amount = ((trade.initial_tokens as f64 * rung.sell_fraction) as u64).min(trade.tokens_held);
+            trade.ladder_progress += 1;
+            if let Some(next_rung) = ladder.get(trade.ladder_progress) {
+                trade.target_price = trade.entry_price_usd * (1.0 + next_rung.gain_percentage / 100.0);
+            }
+
+            if sell_amount == 0 {
+                continue;
+            }
+
+            info!(
+                "Take-profit rung {} hit for {} at ${:.6} (+{:.1}%), selling {} tokens",
+                trade.ladder_progress,
+                trade.token_address,
+                price_update.price_usd,
+                (price_update.price_usd - trade.entry_price_usd) / trade.entry_price_usd * 100.0,
+                sell_amount
+            );
+            trade_queue
+                .push(TradeJob::Exit(ExitOrder {
+                    trade_id: trade_id.clone(),
+                    token_address: trade.token_address.clone(),
+                    sell_amount,
+                    reason: ExitReason::TakeProfit,
+                }))
+                .await;
+            queued = true;
+        }
+
+        // Trailing-stop only arms once at least one ladder rung has already
+        // taken some profit off the table, matching a "sell N%, trail the
+        // rest" ladder design rather than stopping out a fresh position on
+        // ordinary noise.
+        if !queued && trade.ladder_progress > 0 && trade.tokens_held > 0 {
+            let drawdown_pct = (trade.peak_price_usd - price_update.price_usd) / trade.peak_price_usd * 100.0;
+            if drawdown_pct >= trailing_stop_percentage {
+                info!(
+                    "Trailing stop hit for {} ({:.1}% off peak of ${:.6}), selling remaining {} tokens",
+                    trade.token_address, drawdown_pct, trade.peak_price_usd, trade.tokens_held
+                );
+                trade_queue
+                    .push(TradeJob::Exit(ExitOrder {
+                        trade_id: trade_id.clone(),
+                        token_address: trade.token_address.clone(),
+                        sell_amount: trade.tokens_held,
+                        reason: ExitReason::TrailingStop,
+                    }))
+                    .await;
+                queued = true;
+            }
+        }
+
+        if queued {
+            trade.exit_in_flight = true;
+        }
+    }
+}
+
+/// Forces an exit on any position that has been held past
+/// `Config::position_timeout_secs`, regardless of price. Driven by the same
+/// periodic sweep that already prunes stale trades from `active_trades`.
+pub async fn evaluate_timeout_exit(
+    config: &Arc<RwLock<Config>>,
+    active_trades: &Arc<RwLock<HashMap<String, ActiveTrade>>>,
+    trade_queue: &Arc<TradeQueue>,
+) {
+    let position_timeout_secs = config.read().await.position_timeout_secs;
+    let now = crate::utils::get_timestamp();
+
+    let mut trades = active_trades.write().await;
+    for (trade_id, trade) in trades.iter_mut() {
+        if trade.status != TradeStatus::Executing || trade.tokens_held == 0 || trade.exit_in_flight {
+            continue;
+        }
+        if now.saturating_sub(trade.created_at) < position_timeout_secs {
+            continue;
+        }
+
+        info!(
+            "Position {} timed out after {}s, forcing exit of {} tokens",
+            trade.token_address, position_timeout_secs, trade.tokens_held
+        );
+        trade_queue
+            .push(TradeJob::Exit(ExitOrder {
+                trade_id: trade_id.clone(),
+                token_address: trade.token_address.clone(),
+                sell_amount: trade.tokens_held,
+                reason: ExitReason::Timeout,
+            }))
+            .await;
+        trade.exit_in_flight = true;
+    }
+}
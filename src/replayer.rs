@@ -0,0 +1,139 @@
+use anyhow::Result;
+use dashmap::DashMap;
+use tracing::{debug, info, warn};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{signature::Signature, transaction::Transaction};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
+
+use crate::config::Config;
+use crate::tpu_client::TpuClient;
+
+/// A transaction that has been sent once but not yet confirmed or expired.
+#[derive(Debug, Clone)]
+pub struct SentTransactionInfo {
+    pub signature: Signature,
+    pub transaction: Transaction,
+    pub last_valid_blockhash_height: u64,
+    pub sent_at: u64,
+    pub replay_count: u32,
+}
+
+/// Rebroadcasts still-unconfirmed transactions until they either confirm or
+/// their blockhash expires, inspired by lite-rpc's transaction replayer. A
+/// single submission being dropped by its leader no longer means a silent
+/// snipe failure.
+pub struct TransactionReplayer {
+    rpc_client: RpcClient,
+    tpu_client: TpuClient,
+    config: Arc<RwLock<Config>>,
+    in_flight: Arc<DashMap<Signature, SentTransactionInfo>>,
+}
+
+impl TransactionReplayer {
+    pub fn new(config: Arc<RwLock<Config>>, tpu_client: TpuClient) -> Result<Self> {
+        let config_guard = config.read().unwrap();
+        let rpc_client = RpcClient::new(config_guard.solana_rpc_url.clone());
+        drop(config_guard);
+
+        Ok(Self {
+            rpc_client,
+            tpu_client,
+            config,
+            in_flight: Arc::new(DashMap::new()),
+        })
+    }
+
+    /// Register a freshly-sent transaction for rebroadcast tracking.
+    pub fn register(&self, transaction: Transaction, last_valid_blockhash_height: u64) -> Result<Signature> {
+        let signature = transaction
+            .signatures
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("Transaction has no signature to track"))?;
+
+        self.in_flight.insert(
+            signature,
+            SentTransactionInfo {
+                signature,
+                transaction,
+                last_valid_blockhash_height,
+                sent_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+                replay_count: 0,
+            },
+        );
+
+        Ok(signature)
+    }
+
+    /// Stop tracking a transaction, typically once it has confirmed.
+    pub fn deregister(&self, signature: &Signature) {
+        self.in_flight.remove(signature);
+    }
+
+    /// Spawn the background rebroadcast loop. Wakes every `replay_interval_ms`
+    /// and resends every still-registered transaction until it is
+    /// deregistered, its blockhash expires, or it has been resent
+    /// `max_replay_count` times.
+    pub fn start(&self) {
+        let rpc_client_url = self.rpc_client.url();
+        let tpu_client = self.tpu_client.clone();
+        let config = self.config.clone();
+        let in_flight = self.in_flight.clone();
+
+        tokio::spawn(async move {
+            let rpc_client = RpcClient::new(rpc_client_url);
+
+            loop {
+                let config_guard = config.read().await;
+                let interval_ms = config_guard.replay_interval_ms;
+                let max_replay_count = config_guard.max_replay_count;
+                let tpu_fanout = config_guard.tpu_fanout;
+                drop(config_guard);
+
+                tokio::time::sleep(Duration::from_millis(interval_ms)).await;
+
+                let current_height = match rpc_client.get_block_height() {
+                    Ok(height) => height,
+                    Err(e) => {
+                        warn!("Failed to fetch block height for replay loop: {}", e);
+                        continue;
+                    }
+                };
+
+                let mut expired = Vec::new();
+
+                for mut entry in in_flight.iter_mut() {
+                    if current_height > entry.last_valid_blockhash_height {
+                        expired.push(*entry.key());
+                        continue;
+                    }
+
+                    if entry.replay_count >= max_replay_count {
+                        continue;
+                    }
+
+                    match tpu_client.send_transaction(&entry.transaction, tpu_fanout).await {
+                        Ok(_) => {
+                            entry.replay_count += 1;
+                            debug!("Rebroadcast {} (attempt {})", entry.signature, entry.replay_count);
+                        }
+                        Err(e) => {
+                            debug!("Rebroadcast failed for {}: {}", entry.signature, e);
+                        }
+                    }
+                }
+
+                for signature in expired {
+                    in_flight.remove(&signature);
+                    info!("Dropped expired transaction {} (blockhash expired)", signature);
+                }
+            }
+        });
+    }
+
+    pub fn pending_count(&self) -> usize {
+        self.in_flight.len()
+    }
+}
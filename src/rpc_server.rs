@@ -0,0 +1,202 @@
+use anyhow::Result;
+use jsonrpsee::core::async_trait;
+use jsonrpsee::proc_macros::rpc;
+use jsonrpsee::server::ServerBuilder;
+use jsonrpsee::types::ErrorObjectOwned;
+use tracing::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::config::Config;
+use crate::money::Lamports;
+use crate::sniper::{ActiveTrade, TradeStatus};
+use crate::trade_store::TradeStore;
+
+/// Flattened view of an `ActiveTrade` safe to hand back over RPC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeSummary {
+    pub trade_id: String,
+    pub token_address: String,
+    pub status: String,
+    pub submission_id: Option<String>,
+    pub signature: Option<String>,
+}
+
+/// Tunables an operator is allowed to hot-reload without a restart. Every
+/// field is optional so a caller can patch just the one knob they care about.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConfigUpdate {
+    /// SOL, not lamports — converted to `Lamports` when applied.
+    pub min_liquidity: Option<f64>,
+    pub max_slippage: Option<f64>,
+    /// SOL, not lamports — converted to `Lamports` when applied.
+    pub snipe_amount: Option<f64>,
+    pub enable_mev: Option<bool>,
+}
+
+#[rpc(server, namespace = "control")]
+pub trait ControlApi {
+    #[method(name = "listActiveTrades")]
+    async fn list_active_trades(&self) -> Result<Vec<TradeSummary>, ErrorObjectOwned>;
+
+    #[method(name = "cancelTrade")]
+    async fn cancel_trade(&self, token_address: String) -> Result<usize, ErrorObjectOwned>;
+
+    #[method(name = "pauseSnipe")]
+    async fn pause_snipe(&self) -> Result<(), ErrorObjectOwned>;
+
+    #[method(name = "resumeSnipe")]
+    async fn resume_snipe(&self) -> Result<(), ErrorObjectOwned>;
+
+    #[method(name = "pauseMev")]
+    async fn pause_mev(&self) -> Result<(), ErrorObjectOwned>;
+
+    #[method(name = "resumeMev")]
+    async fn resume_mev(&self) -> Result<(), ErrorObjectOwned>;
+
+    #[method(name = "updateConfig")]
+    async fn update_config(&self, update: ConfigUpdate) -> Result<(), ErrorObjectOwned>;
+}
+
+/// Operator control surface for a running `SniperBot`. Every field here is
+/// the same `Arc`-wrapped handle the trade workers already hold, so a
+/// mutation made through RPC is visible to in-flight trades immediately
+/// rather than requiring a restart or a separate sync mechanism.
+pub struct ControlServer {
+    active_trades: Arc<RwLock<HashMap<String, ActiveTrade>>>,
+    trade_store: Arc<TradeStore>,
+    config: Arc<RwLock<Config>>,
+    snipe_paused: Arc<AtomicBool>,
+    mev_paused: Arc<AtomicBool>,
+}
+
+impl ControlServer {
+    pub fn new(
+        active_trades: Arc<RwLock<HashMap<String, ActiveTrade>>>,
+        trade_store: Arc<TradeStore>,
+        config: Arc<RwLock<Config>>,
+        snipe_paused: Arc<AtomicBool>,
+        mev_paused: Arc<AtomicBool>,
+    ) -> Self {
+        Self {
+            active_trades,
+            trade_store,
+            config,
+            snipe_paused,
+            mev_paused,
+        }
+    }
+
+    /// Bind the control server to `port` and hand back a handle that
+    /// resolves once the server stops, so callers can fold it into a
+    /// `tokio::select!` alongside the bot's other background tasks.
+    pub async fn start(self, port: u16) -> Result<tokio::task::JoinHandle<()>> {
+        let server = ServerBuilder::default().build(("0.0.0.0", port)).await?;
+        let addr = server.local_addr()?;
+        let handle = server.start(self.into_rpc());
+        info!("Control RPC server listening on {}", addr);
+
+        Ok(tokio::spawn(async move {
+            handle.stopped().await;
+            warn!("Control RPC server stopped");
+        }))
+    }
+}
+
+fn internal_error(e: impl std::fmt::Display) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(-32000, e.to_string(), None::<()>)
+}
+
+#[async_trait]
+impl ControlApiServer for ControlServer {
+    async fn list_active_trades(&self) -> Result<Vec<TradeSummary>, ErrorObjectOwned> {
+        let trades = self.active_trades.read().await;
+        Ok(trades
+            .iter()
+            .map(|(trade_id, trade)| TradeSummary {
+                trade_id: trade_id.clone(),
+                token_address: trade.token_address.clone(),
+                status: format!("{:?}", trade.status),
+                submission_id: trade.submission_id.clone(),
+                signature: trade.signature.clone(),
+            })
+            .collect())
+    }
+
+    async fn cancel_trade(&self, token_address: String) -> Result<usize, ErrorObjectOwned> {
+        let mut trades = self.active_trades.write().await;
+        let mut cancelled = 0usize;
+
+        for (trade_id, trade) in trades.iter_mut() {
+            if trade.token_address != token_address {
+                continue;
+            }
+            if trade.status != TradeStatus::Pending && trade.status != TradeStatus::Executing {
+                continue;
+            }
+
+            trade.status = TradeStatus::Cancelled;
+            if let Err(e) = self.trade_store.put(trade_id, trade) {
+                warn!("Failed to persist cancellation of trade {}: {}", trade_id, e);
+            }
+            cancelled += 1;
+        }
+
+        if cancelled == 0 {
+            warn!("Cancel request for {} matched no pending/executing trade", token_address);
+        } else {
+            info!("Cancelled {} trade(s) for {}", cancelled, token_address);
+        }
+
+        Ok(cancelled)
+    }
+
+    async fn pause_snipe(&self) -> Result<(), ErrorObjectOwned> {
+        self.snipe_paused.store(true, Ordering::SeqCst);
+        info!("Snipe subsystem paused via control RPC");
+        Ok(())
+    }
+
+    async fn resume_snipe(&self) -> Result<(), ErrorObjectOwned> {
+        self.snipe_paused.store(false, Ordering::SeqCst);
+        info!("Snipe subsystem resumed via control RPC");
+        Ok(())
+    }
+
+    async fn pause_mev(&self) -> Result<(), ErrorObjectOwned> {
+        self.mev_paused.store(true, Ordering::SeqCst);
+        info!("MEV subsystem paused via control RPC");
+        Ok(())
+    }
+
+    async fn resume_mev(&self) -> Result<(), ErrorObjectOwned> {
+        self.mev_paused.store(false, Ordering::SeqCst);
+        info!("MEV subsystem resumed via control RPC");
+        Ok(())
+    }
+
+    async fn update_config(&self, update: ConfigUpdate) -> Result<(), ErrorObjectOwned> {
+        if update.max_slippage.map_or(false, |v| !(0.0..=100.0).contains(&v)) {
+            return Err(internal_error("max_slippage must be between 0 and 100"));
+        }
+
+        let mut config = self.config.write().await;
+        if let Some(min_liquidity) = update.min_liquidity {
+            config.min_liquidity = Lamports::from_sol(min_liquidity);
+        }
+        if let Some(max_slippage) = update.max_slippage {
+            config.max_slippage = max_slippage;
+        }
+        if let Some(snipe_amount) = update.snipe_amount {
+            config.snipe_amount = Lamports::from_sol(snipe_amount);
+        }
+        if let Some(enable_mev) = update.enable_mev {
+            config.enable_mev = enable_mev;
+        }
+        info!("Config hot-reloaded via control RPC: {:?}", update);
+        Ok(())
+    }
+}
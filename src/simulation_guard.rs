@@ -0,0 +1,135 @@
+use anyhow::{anyhow, Result};
+use tracing::info;
+use solana_account_decoder::{UiAccount, UiAccountEncoding};
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig},
+};
+use solana_sdk::{commitment_config::CommitmentConfig, program_pack::Pack, pubkey::Pubkey, transaction::Transaction};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::Account as TokenAccount;
+
+/// Post-state bounds a simulated fill must satisfy, derived from a trade's
+/// configured slippage and spend limits.
+pub struct FillAssertion {
+    pub min_tokens_out: u64,
+    pub max_lamports_spent: u64,
+}
+
+/// Replays a built transaction through `simulateTransaction` and asserts the
+/// realized token/SOL deltas before it's allowed to leave the process for a
+/// confirmation backend. Several concurrent snipes racing into the same
+/// freshly-listed pool is exactly the scenario where a sane `max_slippage`
+/// setting can still produce a terrible fill, so this exists to reject that
+/// *before* capital leaves the wallet instead of after.
+///
+/// This only catches what the simulation sees at submission time; a
+/// transaction that lands several slots later than it simulated can still
+/// realize a worse fill than was asserted here. An on-chain assertion
+/// instruction that fails the transaction atomically if the realized output
+/// falls below threshold would close that gap, but requires a program we
+/// don't control on-chain to host it, so it's left as a follow-up rather
+/// than faked here.
+pub struct SimulationGuard {
+    rpc_client: RpcClient,
+}
+
+impl SimulationGuard {
+    pub fn new(rpc_url: String) -> Self {
+        Self {
+            rpc_client: RpcClient::new(rpc_url),
+        }
+    }
+
+    /// Simulate `transaction` and assert the realized fill for `owner`'s
+    /// `token_mint` associated token account, plus SOL spent from `owner`,
+    /// are within `assertion`. Returns `Err` if the simulation itself fails
+    /// or either bound is violated; callers should abort submission on `Err`.
+    pub fn assert_safe_fill(
+        &self,
+        transaction: &Transaction,
+        owner: &Pubkey,
+        token_mint: &Pubkey,
+        assertion: &FillAssertion,
+    ) -> Result<()> {
+        let ata = get_associated_token_address(owner, token_mint);
+
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            commitment: Some(CommitmentConfig::processed()),
+            accounts: Some(RpcSimulateTransactionAccountsConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                addresses: vec![ata.to_string(), owner.to_string()],
+            }),
+            ..RpcSimulateTransactionConfig::default()
+        };
+
+        let response = self
+            .rpc_client
+            .simulate_transaction_with_config(transaction, config)
+            .map_err(|e| anyhow!("Simulation RPC call failed: {}", e))?;
+
+        if let Some(err) = response.value.err {
+            return Err(anyhow!(
+                "Simulated transaction would fail: {:?} (logs: {:?})",
+                err,
+                response.value.logs
+            ));
+        }
+
+        let accounts = response
+            .value
+            .accounts
+            .ok_or_else(|| anyhow!("Simulation returned no post-state accounts to assert against"))?;
+
+        let tokens_out = Self::decode_token_balance(&accounts, 0)
+            .map_err(|e| anyhow!("Could not verify realized token output: {}", e))?;
+        if tokens_out < assertion.min_tokens_out {
+            return Err(anyhow!(
+                "Simulated fill of {} tokens is below the {} minimum implied by max_slippage",
+                tokens_out,
+                assertion.min_tokens_out
+            ));
+        }
+
+        let lamports_after = accounts
+            .get(1)
+            .and_then(|a| a.as_ref())
+            .map(|a| a.lamports)
+            .ok_or_else(|| anyhow!("Simulation did not return the wallet account"))?;
+        let lamports_before = self.rpc_client.get_balance(owner).unwrap_or(lamports_after);
+        let lamports_spent = lamports_before.saturating_sub(lamports_after);
+        if lamports_spent > assertion.max_lamports_spent {
+            return Err(anyhow!(
+                "Simulated spend of {} lamports exceeds the configured {} lamport cap",
+                lamports_spent,
+                assertion.max_lamports_spent
+            ));
+        }
+
+        info!(
+            "Pre-submit simulation passed: {} tokens out, {} lamports spent",
+            tokens_out, lamports_spent
+        );
+
+        Ok(())
+    }
+
+    fn decode_token_balance(accounts: &[Option<UiAccount>], index: usize) -> Result<u64> {
+        let account = accounts
+            .get(index)
+            .and_then(|a| a.as_ref())
+            .ok_or_else(|| anyhow!("destination token account missing from simulation result"))?;
+
+        let data = account
+            .data
+            .decode()
+            .ok_or_else(|| anyhow!("could not decode simulated token account data"))?;
+
+        let token_account = TokenAccount::unpack(&data)
+            .map_err(|e| anyhow!("simulated account is not a valid SPL token account: {}", e))?;
+
+        Ok(token_account.amount)
+    }
+}
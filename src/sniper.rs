@@ -1,46 +1,95 @@
 use anyhow::Result;
-use log::{debug, error, info, warn};
+use tracing::{debug, error, info, warn};
+use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
-    instruction::Instruction,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
     transaction::Transaction,
 };
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
-use tokio::sync::{RwLock, Semaphore};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
 
-use crate::config::Config;
+use crate::config::{Config, OracleSource};
+use crate::error_tracking::ErrorTracker;
+use crate::fee_oracle::FeeOracle;
 use crate::grpc_client::{PumpSwapGrpcClient, TokenListingStream, PriceUpdateStream};
 use crate::jito_client::BundleManager;
+use crate::jupiter_client::JupiterClient;
+use crate::latency_metrics::LatencyMetrics;
 use crate::mev_detector::{MEVDetector, MEVSignal, MEVPriority};
+use crate::monitoring::Monitoring;
 use crate::nozomi_client::NozomiManager;
+use crate::oracle_aggregator::OracleAggregator;
+use crate::pool_state_retriever::ScanningRetriever;
 use crate::proto::pumpswap::*;
+use crate::rpc_server::ControlServer;
+use crate::simulation_guard::{FillAssertion, SimulationGuard};
+use crate::state_guard::{PoolStateSnapshot, StateGuard};
+use crate::tpu_client::TpuClient;
+use crate::trade_store::TradeStore;
+use crate::rebalancer;
+use crate::work_queue::{ExitOrder, TradeJob, TradeQueue};
 
 pub struct SniperBot {
     config: Arc<RwLock<Config>>,
     grpc_client: PumpSwapGrpcClient,
     jito_manager: Option<BundleManager>,
-    nozomi_manager: Option<NozomiManager>,
+    nozomi_manager: Option<Arc<NozomiManager>>,
+    tpu_client: Option<TpuClient>,
     mev_detector: MEVDetector,
     wallet: Keypair,
-    active_trades: std::collections::HashMap<String, ActiveTrade>,
-    trade_semaphore: Arc<Semaphore>,
+    active_trades: Arc<RwLock<HashMap<String, ActiveTrade>>>,
+    max_concurrent_trades: usize,
+    trade_queue: Arc<TradeQueue>,
+    simulation_guard: Arc<SimulationGuard>,
+    state_guard: Arc<StateGuard>,
+    latency_metrics: Arc<LatencyMetrics>,
+    trade_store: Arc<TradeStore>,
+    snipe_paused: Arc<AtomicBool>,
+    mev_paused: Arc<AtomicBool>,
+    error_tracker: Arc<ErrorTracker>,
+    oracle_aggregator: Arc<OracleAggregator>,
 }
 
 #[derive(Debug, Clone)]
-struct ActiveTrade {
+pub(crate) struct ActiveTrade {
     pub token_address: String,
     pub amount: u64,
+    /// Absolute USD price that triggers the next profit-ladder rung; 0.0
+    /// until `entry_price_usd` is established, then recomputed every time a
+    /// rung fires.
     pub target_price: f64,
     pub max_slippage: f64,
     pub created_at: u64,
     pub status: TradeStatus,
+    pub submission_id: Option<String>,
+    pub signature: Option<String>,
+    /// Cost basis in USD, set to the first price observed for this token
+    /// after the buy lands (there's no fill-price oracle to read it from
+    /// directly).
+    pub entry_price_usd: f64,
+    /// Highest price observed since entry, used as the trailing-stop anchor.
+    pub peak_price_usd: f64,
+    /// Tokens still held for this position, in the token's smallest unit.
+    pub tokens_held: u64,
+    /// Tokens acquired by the original buy; ladder rungs sell a fraction of
+    /// this rather than of whatever remains, so later rungs aren't shrunk by
+    /// earlier ones.
+    pub initial_tokens: u64,
+    /// Index of the next un-fired rung in `Config::profit_ladder`.
+    pub ladder_progress: usize,
+    /// Set while an exit job for this trade is queued or executing, so the
+    /// rebalancer doesn't queue a second one before the first resolves.
+    pub exit_in_flight: bool,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-enum TradeStatus {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TradeStatus {
     Pending,
     Executing,
     Completed,
@@ -52,21 +101,32 @@ impl SniperBot {
     pub async fn new(config: Arc<RwLock<Config>>) -> Result<Self> {
         // Initialize gRPC client
         let grpc_client = PumpSwapGrpcClient::new(config.clone()).await?;
-        
+
+        // Adaptive base-fee tracker feeding the jito backend's priority fee.
+        let fee_oracle = Arc::new(FeeOracle::new(config.clone()));
+
         // Initialize confirmation service managers
         let config_guard = config.read().await;
         let jito_manager = if config_guard.confirmation_service == "jito" {
-            Some(BundleManager::new(config.clone())?)
+            Some(BundleManager::new(config.clone(), fee_oracle.clone())?)
         } else {
             None
         };
         
         let nozomi_manager = if config_guard.confirmation_service == "nozomi" {
-            Some(NozomiManager::new(config.clone())?)
+            Some(Arc::new(NozomiManager::new(config.clone()).await?))
         } else {
             None
         };
-        
+
+        let tpu_client = if config_guard.confirmation_service == "tpu" {
+            let tpu_client = TpuClient::new(config.clone())?;
+            tpu_client.start_background_tasks();
+            Some(tpu_client)
+        } else {
+            None
+        };
+
         // Initialize wallet
         let private_key = &config_guard.private_key;
         let wallet = if private_key.starts_with('[') {
@@ -80,117 +140,297 @@ impl SniperBot {
         };
         
         let max_concurrent_trades = config_guard.max_concurrent_trades;
+        let trade_queue_capacity = config_guard.trade_queue_capacity;
+        let trade_db_path = config_guard.trade_db_path.clone();
+        let solana_rpc_url = config_guard.solana_rpc_url.clone();
         drop(config_guard);
-        
+
         // Initialize MEV detector
         let mev_detector = MEVDetector::new(config.clone());
-        
+
+        // Reload persisted trades and reconcile any still-open ones against
+        // on-chain state before we start monitoring, so a crash mid-execution
+        // doesn't leave the bot unaware of a token it still needs to sell.
+        let trade_store = Arc::new(TradeStore::open(&trade_db_path)?);
+        let rpc_client = RpcClient::new(solana_rpc_url.clone());
+        let active_trades = Self::reconcile_trades(&trade_store, &rpc_client).await;
+        let error_tracker = Arc::new(ErrorTracker::new(config.clone()));
+        let oracle_aggregator = Arc::new(OracleAggregator::new(config.clone()));
+
         Ok(Self {
             config,
             grpc_client,
             jito_manager,
             nozomi_manager,
+            tpu_client,
             mev_detector,
             wallet,
-            active_trades: std::collections::HashMap::new(),
-            trade_semaphore: Arc::new(Semaphore::new(max_concurrent_trades)),
+            active_trades: Arc::new(RwLock::new(active_trades)),
+            max_concurrent_trades,
+            trade_queue: Arc::new(TradeQueue::new(trade_queue_capacity)),
+            simulation_guard: Arc::new(SimulationGuard::new(solana_rpc_url.clone())),
+            state_guard: Arc::new(StateGuard::new(solana_rpc_url)),
+            latency_metrics: Arc::new(LatencyMetrics::new()),
+            trade_store,
+            snipe_paused: Arc::new(AtomicBool::new(false)),
+            mev_paused: Arc::new(AtomicBool::new(false)),
+            error_tracker,
+            oracle_aggregator,
         })
     }
-    
+
+    /// Load every persisted trade and, for ones still `Pending`/`Executing`,
+    /// check whether the stored signature actually landed while we were
+    /// down. Finalized trades are updated in the store; everything still
+    /// open is handed back to resume monitoring.
+    async fn reconcile_trades(
+        trade_store: &TradeStore,
+        rpc_client: &RpcClient,
+    ) -> std::collections::HashMap<String, ActiveTrade> {
+        let mut trades = match trade_store.load_all() {
+            Ok(trades) => trades,
+            Err(e) => {
+                warn!("Failed to load persisted trades: {}", e);
+                return std::collections::HashMap::new();
+            }
+        };
+
+        for (trade_id, trade) in trades.iter_mut() {
+            if trade.status != TradeStatus::Pending && trade.status != TradeStatus::Executing {
+                continue;
+            }
+
+            let Some(signature) = trade.signature.as_deref().and_then(|s| s.parse().ok()) else {
+                continue;
+            };
+
+            match rpc_client.get_signature_status(&signature) {
+                Ok(Some(Ok(()))) => {
+                    info!("Reconciled trade {} as confirmed on-chain", trade_id);
+                    trade.status = TradeStatus::Completed;
+                }
+                Ok(Some(Err(e))) => {
+                    warn!("Reconciled trade {} as failed on-chain: {}", trade_id, e);
+                    trade.status = TradeStatus::Failed;
+                }
+                Ok(None) => {
+                    warn!("Trade {} has no on-chain record, marking failed", trade_id);
+                    trade.status = TradeStatus::Failed;
+                }
+                Err(e) => {
+                    warn!("Could not reconcile trade {}, leaving status as-is: {}", trade_id, e);
+                    continue;
+                }
+            }
+
+            if let Err(e) = trade_store.put(trade_id, trade) {
+                warn!("Failed to persist reconciled trade {}: {}", trade_id, e);
+            }
+        }
+
+        trades.retain(|_, trade| trade.status == TradeStatus::Pending || trade.status == TradeStatus::Executing);
+        trades
+    }
+
     pub async fn start(&mut self) -> Result<()> {
         info!("Starting PumpSwap 0-Slot Sniper Bot");
-        
+
+        self.latency_metrics.clone().start_reporter(Duration::from_secs(30));
+
+        // Start the control RPC server so an operator can inspect or steer a
+        // live bot (cancel a trade, pause a subsystem, hot-reload a tunable)
+        // without killing the process.
+        let control_server_handle = if self.config.read().await.enable_control_server {
+            let port = self.config.read().await.control_server_port;
+            let server = ControlServer::new(
+                self.active_trades.clone(),
+                self.trade_store.clone(),
+                self.config.clone(),
+                self.snipe_paused.clone(),
+                self.mev_paused.clone(),
+            );
+            Some(server.start(port).await?)
+        } else {
+            None
+        };
+        let control_server_task = tokio::spawn(async move {
+            match control_server_handle {
+                Some(handle) => {
+                    let _ = handle.await;
+                }
+                None => futures::future::pending::<()>().await,
+            }
+        });
+
+        // Spin up the trade worker pool that drains `trade_queue`. Detection
+        // only ever pushes onto this queue; build/submit work happens here so
+        // a slow Jupiter quote can't stall the gRPC stream callbacks below.
+        let trade_workers_handle = {
+            let queue_rx = self.trade_queue.receiver();
+            let worker_handles: Vec<_> = (0..self.max_concurrent_trades.max(1))
+                .map(|worker_id| {
+                    let queue_rx = queue_rx.clone();
+                    let config = self.config.clone();
+                    let wallet = self.wallet.clone();
+                    let jito_manager = self.jito_manager.clone();
+                    let nozomi_manager = self.nozomi_manager.clone();
+                    let tpu_client = self.tpu_client.clone();
+                    let simulation_guard = self.simulation_guard.clone();
+                    let state_guard = self.state_guard.clone();
+                    let latency_metrics = self.latency_metrics.clone();
+                    let trade_store = self.trade_store.clone();
+                    let active_trades = self.active_trades.clone();
+                    let error_tracker = self.error_tracker.clone();
+
+                    tokio::spawn(async move {
+                        Self::run_trade_worker(
+                            worker_id,
+                            queue_rx,
+                            config,
+                            wallet,
+                            jito_manager,
+                            nozomi_manager,
+                            tpu_client,
+                            simulation_guard,
+                            state_guard,
+                            latency_metrics,
+                            trade_store,
+                            active_trades,
+                            error_tracker,
+                        ).await;
+                    })
+                })
+                .collect();
+
+            tokio::spawn(async move {
+                futures::future::join_all(worker_handles).await;
+            })
+        };
+
         // Start token listing stream
         let token_stream_handle = {
             let config = self.config.clone();
             let mev_detector = self.mev_detector.clone();
-            let wallet = self.wallet.clone();
-            let trade_semaphore = self.trade_semaphore.clone();
-            let mut jito_manager = self.jito_manager.clone();
-            let mut nozomi_manager = self.nozomi_manager.clone();
-            
+            let trade_queue = self.trade_queue.clone();
+            let snipe_paused = self.snipe_paused.clone();
+            let mev_paused = self.mev_paused.clone();
+            let error_tracker = self.error_tracker.clone();
+            let state_guard = self.state_guard.clone();
+            let oracle_aggregator = self.oracle_aggregator.clone();
+
             tokio::spawn(async move {
-                let mut stream = TokenListingStream::new(config.clone());
+                let stream = TokenListingStream::new(config.clone());
                 if let Err(e) = stream.start_streaming(|listing| {
                     let config = config.clone();
                     let mev_detector = mev_detector.clone();
-                    let wallet = wallet.clone();
-                    let trade_semaphore = trade_semaphore.clone();
-                    let mut jito_manager = jito_manager.clone();
-                    let mut nozomi_manager = nozomi_manager.clone();
-                    
+                    let trade_queue = trade_queue.clone();
+                    let snipe_paused = snipe_paused.clone();
+                    let mev_paused = mev_paused.clone();
+                    let error_tracker = error_tracker.clone();
+                    let state_guard = state_guard.clone();
+                    let oracle_aggregator = oracle_aggregator.clone();
+
                     tokio::spawn(async move {
+                        let token_address = listing.token_address.clone();
                         if let Err(e) = Self::process_new_listing(
                             listing,
                             config,
                             mev_detector,
-                            wallet,
-                            trade_semaphore,
-                            jito_manager,
-                            nozomi_manager,
+                            trade_queue,
+                            snipe_paused,
+                            mev_paused,
+                            error_tracker.clone(),
+                            state_guard,
+                            oracle_aggregator,
                         ).await {
                             error!("Error processing new listing: {}", e);
+                            error_tracker.record_failure(&token_address, "snipe", &e.to_string());
                         }
                     });
-                    
+
                     Ok(true) // Continue streaming
                 }).await {
                     error!("Token listing stream error: {}", e);
                 }
             })
         };
-        
+
         // Start price update stream
         let price_stream_handle = {
             let config = self.config.clone();
             let target_tokens = self.config.read().await.target_tokens.clone();
             let mev_detector = self.mev_detector.clone();
-            let wallet = self.wallet.clone();
-            let trade_semaphore = self.trade_semaphore.clone();
-            let mut jito_manager = self.jito_manager.clone();
-            let mut nozomi_manager = self.nozomi_manager.clone();
-            
+            let trade_queue = self.trade_queue.clone();
+            let mev_paused = self.mev_paused.clone();
+            let active_trades = self.active_trades.clone();
+            let error_tracker = self.error_tracker.clone();
+            let oracle_aggregator = self.oracle_aggregator.clone();
+
             tokio::spawn(async move {
-                let mut stream = PriceUpdateStream::new(config.clone(), target_tokens).await.unwrap();
+                let stream = PriceUpdateStream::new(config.clone(), target_tokens).await.unwrap();
                 if let Err(e) = stream.start_streaming(|price_update| {
                     let config = config.clone();
                     let mev_detector = mev_detector.clone();
-                    let wallet = wallet.clone();
-                    let trade_semaphore = trade_semaphore.clone();
-                    let mut jito_manager = jito_manager.clone();
-                    let mut nozomi_manager = nozomi_manager.clone();
-                    
+                    let trade_queue = trade_queue.clone();
+                    let mev_paused = mev_paused.clone();
+                    let active_trades = active_trades.clone();
+                    let error_tracker = error_tracker.clone();
+                    let oracle_aggregator = oracle_aggregator.clone();
+
                     tokio::spawn(async move {
+                        let token_address = price_update.token_address.clone();
                         if let Err(e) = Self::process_price_update(
                             price_update,
                             config,
                             mev_detector,
-                            wallet,
-                            trade_semaphore,
-                            jito_manager,
-                            nozomi_manager,
+                            trade_queue,
+                            mev_paused,
+                            active_trades,
+                            error_tracker.clone(),
+                            oracle_aggregator,
                         ).await {
                             error!("Error processing price update: {}", e);
+                            error_tracker.record_failure(&token_address, "mev", &e.to_string());
                         }
                     });
-                    
+
                     Ok(true) // Continue streaming
                 }).await {
                     error!("Price update stream error: {}", e);
                 }
             })
         };
-        
+
+        // Poll for MEV candidates independently of trade execution, following
+        // the same split the liquidator bot uses between candidate discovery
+        // and transaction building/sending: a slow build/submit never blocks
+        // the next poll, and a slow poll never blocks workers draining jobs
+        // already queued.
+        let mev_poller_handle = {
+            let config = self.config.clone();
+            let mev_detector = self.mev_detector.clone();
+            let trade_queue = self.trade_queue.clone();
+            let mev_paused = self.mev_paused.clone();
+            let error_tracker = self.error_tracker.clone();
+
+            tokio::spawn(async move {
+                Self::run_mev_poller(config, mev_detector, trade_queue, mev_paused, error_tracker).await;
+            })
+        };
+
         // Start trade monitoring
         let trade_monitor_handle = {
-            let active_trades = Arc::new(RwLock::new(self.active_trades.clone()));
+            let active_trades = self.active_trades.clone();
             let config = self.config.clone();
-            
+            let trade_queue = self.trade_queue.clone();
+
             tokio::spawn(async move {
                 let mut interval = tokio::time::interval(Duration::from_secs(1));
                 loop {
                     interval.tick().await;
-                    
+
+                    rebalancer::evaluate_timeout_exit(&config, &active_trades, &trade_queue).await;
+
                     // Clean up completed trades
                     let mut trades = active_trades.write().await;
                     trades.retain(|_, trade| {
@@ -198,15 +438,15 @@ impl SniperBot {
                             .duration_since(UNIX_EPOCH)
                             .unwrap()
                             .as_secs() - trade.created_at;
-                        
+
                         elapsed < 300 // Keep trades for 5 minutes
                     });
                 }
             })
         };
-        
+
         info!("Sniper bot started successfully");
-        
+
         // Wait for streams to complete
         tokio::select! {
             _ = token_stream_handle => {
@@ -218,126 +458,325 @@ impl SniperBot {
             _ = trade_monitor_handle => {
                 info!("Trade monitor completed");
             }
+            _ = mev_poller_handle => {
+                info!("MEV poller completed");
+            }
+            _ = trade_workers_handle => {
+                info!("Trade worker pool completed");
+            }
+            _ = control_server_task => {
+                info!("Control server task completed");
+            }
         }
-        
+
         Ok(())
     }
-    
+
+    /// Pull jobs off the shared trade queue and run them to completion one
+    /// at a time. Running `max_concurrent_trades` of these concurrently is
+    /// what bounds in-flight builds/submissions now; there's no separate
+    /// semaphore because the worker count already caps concurrency.
+    async fn run_trade_worker(
+        worker_id: usize,
+        queue_rx: Arc<tokio::sync::Mutex<tokio::sync::mpsc::Receiver<TradeJob>>>,
+        config: Arc<RwLock<Config>>,
+        wallet: Keypair,
+        mut jito_manager: Option<BundleManager>,
+        nozomi_manager: Option<Arc<NozomiManager>>,
+        mut tpu_client: Option<TpuClient>,
+        simulation_guard: Arc<SimulationGuard>,
+        state_guard: Arc<StateGuard>,
+        latency_metrics: Arc<LatencyMetrics>,
+        trade_store: Arc<TradeStore>,
+        active_trades: Arc<RwLock<HashMap<String, ActiveTrade>>>,
+        error_tracker: Arc<ErrorTracker>,
+    ) {
+        loop {
+            // Only hold the receiver lock long enough to pop a job so other
+            // workers aren't blocked while this one builds/submits.
+            let job = {
+                let mut rx = queue_rx.lock().await;
+                rx.recv().await
+            };
+
+            let Some(job) = job else {
+                info!("Trade worker {} shutting down: queue closed", worker_id);
+                break;
+            };
+
+            let (job_token, job_operation) = match &job {
+                TradeJob::Snipe(listing) => (listing.token_address.clone(), "snipe"),
+                TradeJob::Mev(signal) => (signal.opportunity.token_address.clone(), "mev"),
+                TradeJob::Exit(order) => (order.token_address.clone(), "exit"),
+            };
+
+            let result = match job {
+                TradeJob::Snipe(listing) => Self::execute_snipe(
+                    &listing,
+                    &config,
+                    &wallet,
+                    &mut jito_manager,
+                    &nozomi_manager,
+                    &mut tpu_client,
+                    &simulation_guard,
+                    &state_guard,
+                    &latency_metrics,
+                    &trade_store,
+                    &active_trades,
+                ).await,
+                TradeJob::Mev(signal) => Self::execute_mev_strategy(
+                    &signal,
+                    &config,
+                    &wallet,
+                    &mut jito_manager,
+                    &nozomi_manager,
+                    &mut tpu_client,
+                    &simulation_guard,
+                    &latency_metrics,
+                    &trade_store,
+                    &active_trades,
+                ).await,
+                TradeJob::Exit(order) => Self::execute_exit(
+                    &order,
+                    &config,
+                    &wallet,
+                    &mut jito_manager,
+                    &nozomi_manager,
+                    &mut tpu_client,
+                    &latency_metrics,
+                    &trade_store,
+                    &active_trades,
+                ).await,
+            };
+
+            match &result {
+                Ok(()) => error_tracker.record_success(&job_token, job_operation),
+                Err(e) => error_tracker.record_failure(&job_token, job_operation, &e.to_string()),
+            }
+
+            if let Err(e) = result {
+                error!("Trade worker {} failed to execute job: {}", worker_id, e);
+            }
+        }
+    }
+
+    /// Polls `PumpSwapGrpcClient::get_mev_opportunities` on its own interval
+    /// and feeds candidates into `trade_queue`, decoupled from the worker
+    /// pool that builds/submits them. The queue is the only thing slow
+    /// execution ever backs up; it still applies its own priority-based
+    /// backpressure, and a candidate that sat in flight past its own
+    /// staleness deadline is dropped here before it ever reaches a worker,
+    /// since pump.fun listings can go stale within a fraction of a second.
+    async fn run_mev_poller(
+        config: Arc<RwLock<Config>>,
+        mev_detector: MEVDetector,
+        trade_queue: Arc<TradeQueue>,
+        mev_paused: Arc<AtomicBool>,
+        error_tracker: Arc<ErrorTracker>,
+    ) {
+        let mut client = match PumpSwapGrpcClient::new(config.clone()).await {
+            Ok(client) => client,
+            Err(e) => {
+                error!("MEV poller failed to connect: {}", e);
+                return;
+            }
+        };
+
+        loop {
+            let (poll_interval_ms, staleness_ms, target_tokens, enable_mev) = {
+                let config_guard = config.read().await;
+                (
+                    config_guard.mev_poll_interval_ms,
+                    config_guard.mev_opportunity_staleness_ms,
+                    config_guard.target_tokens.clone(),
+                    config_guard.enable_mev,
+                )
+            };
+
+            tokio::time::sleep(Duration::from_millis(poll_interval_ms)).await;
+
+            if !enable_mev || mev_paused.load(Ordering::SeqCst) {
+                continue;
+            }
+
+            let opportunities = match client.get_mev_opportunities(target_tokens).await {
+                Ok(response) => response.opportunities,
+                Err(e) => {
+                    warn!("Failed to poll MEV opportunities: {}", e);
+                    continue;
+                }
+            };
+
+            Monitoring::update_queue_size(trade_queue.len());
+
+            let now_ms = crate::utils::get_timestamp_ms();
+
+            for raw in opportunities {
+                let opportunity = crate::mev_detector::MEVOpportunity::from_proto(raw);
+
+                if error_tracker.should_skip(&opportunity.token_address, "mev").await {
+                    continue;
+                }
+
+                let age_ms = now_ms.saturating_sub(opportunity.created_at.saturating_mul(1000));
+                if age_ms > staleness_ms {
+                    debug!("Dropping stale MEV opportunity {} ({}ms old)", opportunity.id, age_ms);
+                    continue;
+                }
+
+                match mev_detector.signal_from_opportunity(opportunity).await {
+                    Ok(signal) => trade_queue.push(TradeJob::Mev(signal)).await,
+                    Err(e) => error!("Failed to build execution plan for MEV opportunity: {}", e),
+                }
+            }
+        }
+    }
+
     async fn process_new_listing(
         listing: TokenListing,
         config: Arc<RwLock<Config>>,
         mut mev_detector: MEVDetector,
-        wallet: Keypair,
-        trade_semaphore: Arc<Semaphore>,
-        mut jito_manager: Option<BundleManager>,
-        mut nozomi_manager: Option<NozomiManager>,
+        trade_queue: Arc<TradeQueue>,
+        snipe_paused: Arc<AtomicBool>,
+        mev_paused: Arc<AtomicBool>,
+        error_tracker: Arc<ErrorTracker>,
+        state_guard: Arc<StateGuard>,
+        oracle_aggregator: Arc<OracleAggregator>,
     ) -> Result<()> {
         info!("Processing new listing: {} ({})", listing.token_symbol, listing.token_address);
-        
-        // Check if we should snipe this token
-        let should_snipe = Self::should_snipe_token(&listing, &config).await?;
-        
-        if should_snipe {
-            info!("Token {} meets snipe criteria, executing snipe", listing.token_address);
-            
-            // Acquire semaphore to limit concurrent trades
-            let _permit = trade_semaphore.acquire().await?;
-            
-            // Execute snipe
-            if let Err(e) = Self::execute_snipe(
-                &listing,
-                &config,
-                &wallet,
-                &mut jito_manager,
-                &mut nozomi_manager,
-            ).await {
-                error!("Snipe execution failed: {}", e);
-            }
-        }
-        
-        // Analyze for MEV opportunities
-        if config.read().await.enable_mev {
-            let opportunities = mev_detector.analyze_opportunities(&[listing], &[]).await?;
-            
+
+        // Check if we should snipe this token. A token with an unresolved
+        // snipe failure streak (failed sims, no liquidity, blacklisted mint)
+        // is skipped until its cooldown elapses instead of being retried on
+        // every listing/price tick.
+        let should_snipe = !snipe_paused.load(Ordering::SeqCst)
+            && !error_tracker.should_skip(&listing.token_address, "snipe").await
+            && Self::should_snipe_token(&listing, &config, &state_guard, &oracle_aggregator).await?;
+
+        // Analyze for MEV opportunities before the candidate is queued, since
+        // queuing moves `listing` into the job.
+        if config.read().await.enable_mev && !mev_paused.load(Ordering::SeqCst) {
+            // No pool/oracle account feed is wired up at this call site yet,
+            // so `analyze_opportunities` falls back to its tracked reserve
+            // snapshot rather than a live on-chain read.
+            let opportunities = mev_detector
+                .analyze_opportunities(std::slice::from_ref(&listing), &[], &ScanningRetriever::new(Vec::new()))
+                .await?;
+
             for signal in opportunities {
                 if signal.priority >= MEVPriority::High {
                     info!("High priority MEV opportunity detected: {:?}", signal.opportunity.strategy);
-                    
-                    // Execute MEV strategy
-                    if let Err(e) = Self::execute_mev_strategy(
-                        &signal,
-                        &config,
-                        &wallet,
-                        &mut jito_manager,
-                        &mut nozomi_manager,
-                    ).await {
-                        error!("MEV strategy execution failed: {}", e);
-                    }
+                    trade_queue.push(TradeJob::Mev(signal)).await;
                 }
             }
         }
-        
+
+        if should_snipe {
+            info!("Token {} meets snipe criteria, queuing snipe", listing.token_address);
+            trade_queue.push(TradeJob::Snipe(listing)).await;
+        }
+
         Ok(())
     }
-    
+
     async fn process_price_update(
         price_update: PriceUpdate,
         config: Arc<RwLock<Config>>,
         mut mev_detector: MEVDetector,
-        wallet: Keypair,
-        trade_semaphore: Arc<Semaphore>,
-        mut jito_manager: Option<BundleManager>,
-        mut nozomi_manager: Option<NozomiManager>,
+        trade_queue: Arc<TradeQueue>,
+        mev_paused: Arc<AtomicBool>,
+        active_trades: Arc<RwLock<HashMap<String, ActiveTrade>>>,
+        error_tracker: Arc<ErrorTracker>,
+        oracle_aggregator: Arc<OracleAggregator>,
     ) -> Result<()> {
         debug!("Processing price update for {}: ${:.6}", price_update.token_address, price_update.price_usd);
-        
+
+        // Record this feed's reading before anything downstream acts on it,
+        // so a reserve read already recorded by `should_snipe_token` (or the
+        // next one) has something fresh to be checked against.
+        oracle_aggregator.record(
+            &price_update.token_address,
+            OracleSource::PumpSwapGrpc,
+            price_update.price_usd,
+            price_update.timestamp,
+        );
+
         // Analyze for MEV opportunities
-        if config.read().await.enable_mev {
-            let opportunities = mev_detector.analyze_opportunities(&[], &[price_update]).await?;
-            
+        if config.read().await.enable_mev
+            && !mev_paused.load(Ordering::SeqCst)
+            && !error_tracker.should_skip(&price_update.token_address, "mev").await
+        {
+            let opportunities = mev_detector
+                .analyze_opportunities(&[], &[price_update.clone()], &ScanningRetriever::new(Vec::new()))
+                .await?;
+
             for signal in opportunities {
                 if signal.priority >= MEVPriority::Medium {
                     info!("MEV opportunity detected: {:?}", signal.opportunity.strategy);
-                    
-                    // Acquire semaphore to limit concurrent trades
-                    let _permit = trade_semaphore.acquire().await?;
-                    
-                    // Execute MEV strategy
-                    if let Err(e) = Self::execute_mev_strategy(
-                        &signal,
-                        &config,
-                        &wallet,
-                        &mut jito_manager,
-                        &mut nozomi_manager,
-                    ).await {
-                        error!("MEV strategy execution failed: {}", e);
-                    }
+                    trade_queue.push(TradeJob::Mev(signal)).await;
                 }
             }
         }
-        
+
+        rebalancer::evaluate_price_exit(&price_update, &config, &active_trades, &trade_queue).await;
+
         Ok(())
     }
     
-    async fn should_snipe_token(listing: &TokenListing, config: &Arc<RwLock<Config>>) -> Result<bool> {
+    async fn should_snipe_token(
+        listing: &TokenListing,
+        config: &Arc<RwLock<Config>>,
+        state_guard: &Arc<StateGuard>,
+        oracle_aggregator: &Arc<OracleAggregator>,
+    ) -> Result<bool> {
         let config_guard = config.read().await;
-        
+
         // Check minimum liquidity
-        if listing.initial_liquidity < (config_guard.min_liquidity * 1e9) as u64 {
+        if listing.initial_liquidity < config_guard.min_liquidity.0 {
             return Ok(false);
         }
-        
+
         // Check if token is in target list
         if !config_guard.target_tokens.is_empty() && !config_guard.target_tokens.contains(&listing.token_address) {
             return Ok(false);
         }
-        
+        drop(config_guard);
+
+        // Feed the pool's own reserve ratio into the oracle aggregator as a
+        // Solana-RPC reading alongside whatever the PumpSwap price stream has
+        // already recorded for this token, so a feed that's lagging or lying
+        // about this listing can't single-handedly wave a snipe through.
+        if let Ok(snapshot) = state_guard.capture(&listing.pool_address) {
+            if snapshot.reserve_in > 0 {
+                let reserve_price = snapshot.reserve_out as f64 / snapshot.reserve_in as f64;
+                oracle_aggregator.record(
+                    &listing.token_address,
+                    OracleSource::SolanaRpcPool,
+                    reserve_price,
+                    crate::utils::get_timestamp(),
+                );
+            }
+        }
+
+        if let Some(consensus) = oracle_aggregator
+            .consensus_price(&listing.token_address, crate::utils::get_timestamp())
+            .await
+        {
+            if consensus.divergent {
+                warn!(
+                    "Oracle sources diverge for {} (agreement {:.2}), skipping snipe",
+                    listing.token_address, consensus.agreement_score
+                );
+                return Ok(false);
+            }
+        }
+
         // Additional criteria can be added here
         // - Token metadata validation
         // - Creator reputation
         // - Liquidity distribution
         // - etc.
-        
+
         Ok(true)
     }
     
@@ -346,117 +785,528 @@ impl SniperBot {
         config: &Arc<RwLock<Config>>,
         wallet: &Keypair,
         jito_manager: &mut Option<BundleManager>,
-        nozomi_manager: &mut Option<NozomiManager>,
+        nozomi_manager: &Option<Arc<NozomiManager>>,
+        tpu_client: &mut Option<TpuClient>,
+        simulation_guard: &Arc<SimulationGuard>,
+        state_guard: &Arc<StateGuard>,
+        latency_metrics: &Arc<LatencyMetrics>,
+        trade_store: &Arc<TradeStore>,
+        active_trades: &Arc<RwLock<HashMap<String, ActiveTrade>>>,
     ) -> Result<()> {
+        let submission_start = Instant::now();
         let config_guard = config.read().await;
-        let snipe_amount = (config_guard.snipe_amount * 1e9) as u64; // Convert SOL to lamports
-        
-        info!("Executing snipe for {} with {} SOL", listing.token_address, config_guard.snipe_amount);
-        
+        let snipe_amount = config_guard.snipe_amount.0;
+        let max_slippage = config_guard.max_slippage;
+        let require_fresh_state = config_guard.require_fresh_state;
+        let max_reserve_drift_bps = config_guard.max_reserve_drift_bps;
+        let transaction_timeout = Duration::from_secs(config_guard.transaction_timeout);
+
+        info!("Executing snipe for {} with {}", listing.token_address, config_guard.snipe_amount);
+
+        // Snapshot the pool's reserves at decision time so they can be
+        // diffed against a fresh read right before submission; a mismatch
+        // means a competing bot already moved this pool this slot.
+        let decision_state: Option<PoolStateSnapshot> = if require_fresh_state {
+            match state_guard.capture(&listing.pool_address) {
+                Ok(snapshot) => Some(snapshot),
+                Err(e) => {
+                    warn!("Could not capture decision-time state for pool {}: {}", listing.pool_address, e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let trade_id = crate::utils::generate_trade_id();
+        let mut trade = ActiveTrade {
+            token_address: listing.token_address.clone(),
+            amount: snipe_amount,
+            target_price: 0.0,
+            max_slippage: config_guard.max_slippage,
+            created_at: crate::utils::get_timestamp(),
+            status: TradeStatus::Pending,
+            submission_id: None,
+            signature: None,
+            entry_price_usd: 0.0,
+            peak_price_usd: 0.0,
+            tokens_held: 0,
+            initial_tokens: 0,
+            ladder_progress: 0,
+            exit_in_flight: false,
+        };
+        if let Err(e) = trade_store.put(&trade_id, &trade) {
+            warn!("Failed to persist new trade {}: {}", trade_id, e);
+        }
+        active_trades.write().await.insert(trade_id.clone(), trade.clone());
+        drop(config_guard);
+
         // Create buy transaction
-        let transaction = Self::create_buy_transaction(
+        let (transaction, tokens_out) = Self::create_buy_transaction(
             &listing.token_address,
             &listing.pool_address,
             snipe_amount,
-            &config_guard.max_slippage,
+            &max_slippage,
             wallet,
+            config,
+            simulation_guard,
         ).await?;
-        
-        // Submit transaction based on confirmation service
-        match config_guard.confirmation_service.as_str() {
-            "jito" => {
-                if let Some(jito_manager) = jito_manager {
-                    let submission_id = jito_manager.submit_transaction(&transaction).await?;
-                    info!("Snipe transaction submitted to Jito: {}", submission_id);
-                } else {
-                    return Err(anyhow::anyhow!("Jito manager not initialized"));
+        let config_guard = config.read().await;
+
+        // The control RPC may have cancelled this trade while the quote and
+        // simulation were in flight; honor that rather than submitting a
+        // transaction the operator already asked us to drop.
+        let cancelled = active_trades
+            .read()
+            .await
+            .get(&trade_id)
+            .map(|t| t.status == TradeStatus::Cancelled)
+            .unwrap_or(false);
+        if cancelled {
+            info!("Snipe for {} was cancelled before submission, skipping", listing.token_address);
+            return Ok(());
+        }
+
+        // Re-observe the pool's state immediately before handing the
+        // transaction to a confirmation backend; if it drifted past the
+        // decision-time snapshot by more than `max_reserve_drift_bps`, or
+        // enough slots have passed to exceed `transaction_timeout`, refuse
+        // to send a bundle whose quoted price no longer matches reality.
+        if let Some(expected) = &decision_state {
+            match state_guard.capture(&listing.pool_address) {
+                Ok(observed) => {
+                    if let Err(e) = crate::utils::validate_state_snapshot(
+                        expected,
+                        &observed,
+                        max_reserve_drift_bps,
+                        transaction_timeout,
+                    ) {
+                        warn!("Aborting snipe for {}: {}", listing.token_address, e);
+                        trade.status = TradeStatus::Failed;
+                        if let Err(store_err) = trade_store.put(&trade_id, &trade) {
+                            warn!("Failed to persist aborted trade {}: {}", trade_id, store_err);
+                        }
+                        active_trades.write().await.insert(trade_id.clone(), trade.clone());
+                        return Err(e);
+                    }
+                }
+                Err(e) => {
+                    warn!("Could not re-observe state for pool {} before submission: {}", listing.pool_address, e);
                 }
             }
-            "nozomi" => {
-                if let Some(nozomi_manager) = nozomi_manager {
-                    let submission_id = nozomi_manager.submit_transaction(&transaction).await?;
-                    info!("Snipe transaction submitted to Nozomi: {}", submission_id);
-                } else {
-                    return Err(anyhow::anyhow!("Nozomi manager not initialized"));
+        }
+
+        trade.signature = transaction.signatures.first().map(|s| s.to_string());
+        trade.status = TradeStatus::Executing;
+        trade.initial_tokens = tokens_out;
+        trade.tokens_held = tokens_out;
+        if let Err(e) = trade_store.put(&trade_id, &trade) {
+            warn!("Failed to persist trade {} as executing: {}", trade_id, e);
+        }
+        active_trades.write().await.insert(trade_id.clone(), trade.clone());
+
+        // Submit transaction based on confirmation service
+        let submission_result = Self::submit_single(
+            &transaction,
+            &config_guard.confirmation_service,
+            config_guard.tpu_fanout,
+            jito_manager,
+            nozomi_manager,
+            tpu_client,
+            trade.signature.clone(),
+        )
+        .await
+        .map(|submission_id| {
+            info!(
+                "Snipe transaction submitted via {} for {}: {}",
+                config_guard.confirmation_service, listing.token_address, submission_id
+            );
+            submission_id
+        });
+
+        match submission_result {
+            Ok(submission_id) => {
+                trade.submission_id = Some(submission_id);
+                if let Err(e) = trade_store.put(&trade_id, &trade) {
+                    warn!("Failed to persist trade {} submission id: {}", trade_id, e);
                 }
+                active_trades.write().await.insert(trade_id.clone(), trade.clone());
             }
-            _ => {
-                return Err(anyhow::anyhow!("Unknown confirmation service: {}", config_guard.confirmation_service));
+            Err(e) => {
+                trade.status = TradeStatus::Failed;
+                if let Err(store_err) = trade_store.put(&trade_id, &trade) {
+                    warn!("Failed to persist failed trade {}: {}", trade_id, store_err);
+                }
+                active_trades.write().await.insert(trade_id.clone(), trade.clone());
+                return Err(e);
             }
         }
-        
+
+        latency_metrics.record(&config_guard.confirmation_service, "snipe", submission_start);
+
         Ok(())
     }
-    
+
     async fn execute_mev_strategy(
         signal: &MEVSignal,
         config: &Arc<RwLock<Config>>,
         wallet: &Keypair,
         jito_manager: &mut Option<BundleManager>,
-        nozomi_manager: &mut Option<NozomiManager>,
+        nozomi_manager: &Option<Arc<NozomiManager>>,
+        tpu_client: &mut Option<TpuClient>,
+        simulation_guard: &Arc<SimulationGuard>,
+        latency_metrics: &Arc<LatencyMetrics>,
+        trade_store: &Arc<TradeStore>,
+        active_trades: &Arc<RwLock<HashMap<String, ActiveTrade>>>,
     ) -> Result<()> {
+        let submission_start = Instant::now();
         info!("Executing MEV strategy: {:?} for token {}", signal.opportunity.strategy, signal.opportunity.token_address);
-        
+
+        let trade_id = crate::utils::generate_trade_id();
+        let mut trade = ActiveTrade {
+            token_address: signal.opportunity.token_address.clone(),
+            amount: 0,
+            target_price: 0.0,
+            max_slippage: signal.execution_plan.max_slippage,
+            created_at: crate::utils::get_timestamp(),
+            status: TradeStatus::Pending,
+            submission_id: None,
+            signature: None,
+            entry_price_usd: 0.0,
+            peak_price_usd: 0.0,
+            tokens_held: 0,
+            initial_tokens: 0,
+            ladder_progress: 0,
+            exit_in_flight: false,
+        };
+        if let Err(e) = trade_store.put(&trade_id, &trade) {
+            warn!("Failed to persist new MEV trade {}: {}", trade_id, e);
+        }
+        active_trades.write().await.insert(trade_id.clone(), trade.clone());
+
         // Create transactions for the MEV strategy
-        let transactions = Self::create_mev_transactions(signal, wallet).await?;
-        
+        let transactions = Self::create_mev_transactions(signal, wallet, config, simulation_guard).await?;
+
+        // The control RPC may have cancelled this trade while the
+        // transactions were being built; honor that before submitting.
+        let cancelled = active_trades
+            .read()
+            .await
+            .get(&trade_id)
+            .map(|t| t.status == TradeStatus::Cancelled)
+            .unwrap_or(false);
+        if cancelled {
+            info!(
+                "MEV strategy for {} was cancelled before submission, skipping",
+                signal.opportunity.token_address
+            );
+            return Ok(());
+        }
+
+        trade.amount = transactions.iter().map(|_| 0u64).sum();
+        trade.status = TradeStatus::Executing;
+        trade.signature = transactions.first().and_then(|t| t.signatures.first()).map(|s| s.to_string());
+        if let Err(e) = trade_store.put(&trade_id, &trade) {
+            warn!("Failed to persist MEV trade {} as executing: {}", trade_id, e);
+        }
+        active_trades.write().await.insert(trade_id.clone(), trade.clone());
+
         // Submit transactions based on confirmation service
         let config_guard = config.read().await;
-        match config_guard.confirmation_service.as_str() {
+        let submission_result: Result<String> = match config_guard.confirmation_service.as_str() {
             "jito" => {
                 if let Some(jito_manager) = jito_manager {
                     let submission_id = jito_manager.submit_transaction_batch(transactions).await?;
                     info!("MEV transactions submitted to Jito: {}", submission_id);
+                    Ok(submission_id)
                 } else {
-                    return Err(anyhow::anyhow!("Jito manager not initialized"));
+                    Err(anyhow::anyhow!("Jito manager not initialized"))
                 }
             }
             "nozomi" => {
                 if let Some(nozomi_manager) = nozomi_manager {
                     let submission_id = nozomi_manager.submit_transaction_batch(transactions).await?;
                     info!("MEV transactions submitted to Nozomi: {}", submission_id);
+                    Ok(submission_id)
                 } else {
-                    return Err(anyhow::anyhow!("Nozomi manager not initialized"));
+                    Err(anyhow::anyhow!("Nozomi manager not initialized"))
+                }
+            }
+            "tpu" => {
+                if let Some(tpu_client) = tpu_client {
+                    for transaction in &transactions {
+                        tpu_client.send_transaction(transaction, config_guard.tpu_fanout).await?;
+                    }
+                    info!("MEV transactions sent direct-to-TPU for {}", signal.opportunity.token_address);
+                    Ok(trade.signature.clone().unwrap_or_default())
+                } else {
+                    Err(anyhow::anyhow!("TPU client not initialized"))
+                }
+            }
+            _ => Err(anyhow::anyhow!("Unknown confirmation service: {}", config_guard.confirmation_service)),
+        };
+
+        match submission_result {
+            Ok(submission_id) => {
+                trade.submission_id = Some(submission_id);
+                if let Err(e) = trade_store.put(&trade_id, &trade) {
+                    warn!("Failed to persist MEV trade {} submission id: {}", trade_id, e);
                 }
+                active_trades.write().await.insert(trade_id.clone(), trade.clone());
             }
-            _ => {
-                return Err(anyhow::anyhow!("Unknown confirmation service: {}", config_guard.confirmation_service));
+            Err(e) => {
+                trade.status = TradeStatus::Failed;
+                if let Err(store_err) = trade_store.put(&trade_id, &trade) {
+                    warn!("Failed to persist failed MEV trade {}: {}", trade_id, store_err);
+                }
+                active_trades.write().await.insert(trade_id.clone(), trade.clone());
+                return Err(e);
             }
         }
-        
+
+        latency_metrics.record(&config_guard.confirmation_service, signal.opportunity.strategy.as_str(), submission_start);
+
         Ok(())
     }
-    
+
+    /// Builds a buy transaction and returns it alongside the minimum tokens
+    /// Jupiter guarantees for the fill, so callers can seed the position's
+    /// `initial_tokens`/`tokens_held` for later rebalancing.
     async fn create_buy_transaction(
         token_address: &str,
         pool_address: &str,
         amount: u64,
         max_slippage: &f64,
         wallet: &Keypair,
-    ) -> Result<Transaction> {
-        // This would create the actual buy transaction
-        // For now, we'll create a placeholder transaction
-        
-        let token_pubkey: Pubkey = token_address.parse()?;
-        let pool_pubkey: Pubkey = pool_address.parse()?;
-        
-        // Create swap instruction (placeholder)
-        let instruction = Instruction {
-            program_id: pool_pubkey, // This would be the actual swap program
-            accounts: vec![], // This would contain the actual accounts
-            data: vec![], // This would contain the actual instruction data
-        };
-        
+        config: &Arc<RwLock<Config>>,
+        simulation_guard: &Arc<SimulationGuard>,
+    ) -> Result<(Transaction, u64)> {
+        let config_guard = config.read().await;
+        let jupiter_url = config_guard.jupiter_api_url.clone();
+        let quote_timeout = Duration::from_millis(config_guard.jupiter_quote_timeout_ms);
+        let enable_simulation_guard = config_guard.enable_simulation_guard;
+        drop(config_guard);
+
+        let output_mint: Pubkey = token_address.parse()?;
+        let input_mint = Pubkey::from_str(crate::jupiter_client::NATIVE_SOL_MINT)?;
+        let slippage_bps = (max_slippage * 100.0).round() as u16;
+
+        let jupiter = JupiterClient::new(jupiter_url);
+
+        let quote = tokio::time::timeout(
+            quote_timeout,
+            jupiter.get_quote(&input_mint, &output_mint, amount, slippage_bps),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("Jupiter quote for pool {} timed out after {:?}", pool_address, quote_timeout))??;
+
+        let min_tokens_out = quote.min_out_amount()?;
+
+        let instructions = tokio::time::timeout(
+            quote_timeout,
+            jupiter.get_swap_instructions(&quote, &wallet.pubkey()),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("Jupiter swap-instructions for pool {} timed out after {:?}", pool_address, quote_timeout))??;
+
         // Create transaction
-        let message = solana_sdk::message::Message::new(&[instruction], Some(&wallet.pubkey()));
+        let message = solana_sdk::message::Message::new(&instructions, Some(&wallet.pubkey()));
         let transaction = Transaction::new(&[wallet], message, solana_sdk::hash::Hash::default());
-        
-        Ok(transaction)
+
+        if enable_simulation_guard {
+            let assertion = FillAssertion {
+                min_tokens_out,
+                max_lamports_spent: amount,
+            };
+            simulation_guard
+                .assert_safe_fill(&transaction, &wallet.pubkey(), &output_mint, &assertion)
+                .map_err(|e| anyhow::anyhow!("Pre-submit simulation for pool {} rejected the fill: {}", pool_address, e))?;
+        }
+
+        Ok((transaction, min_tokens_out))
     }
-    
+
+    /// Builds a sell transaction swapping `token_amount` of `token_address`
+    /// back into SOL. There's no simulation-guard pass here: `FillAssertion`
+    /// is shaped around asserting a minimum token output and a maximum
+    /// lamport spend, which doesn't fit a sell's minimum-lamports-out shape.
+    async fn create_sell_transaction(
+        token_address: &str,
+        token_amount: u64,
+        max_slippage: &f64,
+        wallet: &Keypair,
+        config: &Arc<RwLock<Config>>,
+    ) -> Result<Transaction> {
+        let config_guard = config.read().await;
+        let jupiter_url = config_guard.jupiter_api_url.clone();
+        let quote_timeout = Duration::from_millis(config_guard.jupiter_quote_timeout_ms);
+        drop(config_guard);
+
+        let input_mint: Pubkey = token_address.parse()?;
+        let output_mint = Pubkey::from_str(crate::jupiter_client::NATIVE_SOL_MINT)?;
+        let slippage_bps = (max_slippage * 100.0).round() as u16;
+
+        let jupiter = JupiterClient::new(jupiter_url);
+
+        let quote = tokio::time::timeout(
+            quote_timeout,
+            jupiter.get_quote(&input_mint, &output_mint, token_amount, slippage_bps),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("Jupiter sell quote for {} timed out after {:?}", token_address, quote_timeout))??;
+
+        let instructions = tokio::time::timeout(
+            quote_timeout,
+            jupiter.get_swap_instructions(&quote, &wallet.pubkey()),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("Jupiter sell swap-instructions for {} timed out after {:?}", token_address, quote_timeout))??;
+
+        let message = solana_sdk::message::Message::new(&instructions, Some(&wallet.pubkey()));
+        Ok(Transaction::new(&[wallet], message, solana_sdk::hash::Hash::default()))
+    }
+
+    /// Submit a single transaction through whichever confirmation backend is
+    /// configured. Shared by `execute_snipe` and `execute_exit` so the
+    /// jito/nozomi/tpu dispatch isn't copy-pasted a third time; MEV batches
+    /// still submit through `submit_transaction_batch` directly since this
+    /// only takes one transaction.
+    async fn submit_single(
+        transaction: &Transaction,
+        confirmation_service: &str,
+        tpu_fanout: usize,
+        jito_manager: &mut Option<BundleManager>,
+        nozomi_manager: &Option<Arc<NozomiManager>>,
+        tpu_client: &mut Option<TpuClient>,
+        fallback_signature: Option<String>,
+    ) -> Result<String> {
+        match confirmation_service {
+            "jito" => {
+                if let Some(jito_manager) = jito_manager {
+                    jito_manager.submit_transaction(transaction).await
+                } else {
+                    Err(anyhow::anyhow!("Jito manager not initialized"))
+                }
+            }
+            "nozomi" => {
+                if let Some(nozomi_manager) = nozomi_manager {
+                    nozomi_manager.submit_transaction(transaction).await
+                } else {
+                    Err(anyhow::anyhow!("Nozomi manager not initialized"))
+                }
+            }
+            "tpu" => {
+                if let Some(tpu_client) = tpu_client {
+                    tpu_client.send_transaction(transaction, tpu_fanout).await?;
+                    Ok(fallback_signature.unwrap_or_default())
+                } else {
+                    Err(anyhow::anyhow!("TPU client not initialized"))
+                }
+            }
+            other => Err(anyhow::anyhow!("Unknown confirmation service: {}", other)),
+        }
+    }
+
+    /// Executes a rebalancer-issued exit: builds and submits a sell for
+    /// `order.sell_amount`, then updates the position's remaining balance,
+    /// flipping it to `Completed` once nothing is left to sell.
+    async fn execute_exit(
+        order: &ExitOrder,
+        config: &Arc<RwLock<Config>>,
+        wallet: &Keypair,
+        jito_manager: &mut Option<BundleManager>,
+        nozomi_manager: &Option<Arc<NozomiManager>>,
+        tpu_client: &mut Option<TpuClient>,
+        latency_metrics: &Arc<LatencyMetrics>,
+        trade_store: &Arc<TradeStore>,
+        active_trades: &Arc<RwLock<HashMap<String, ActiveTrade>>>,
+    ) -> Result<()> {
+        let submission_start = Instant::now();
+        info!(
+            "Executing {:?} exit for {} ({}): selling {} tokens",
+            order.reason, order.token_address, order.trade_id, order.sell_amount
+        );
+
+        let trade_max_slippage = active_trades.read().await.get(&order.trade_id).map(|t| t.max_slippage);
+        let max_slippage = match trade_max_slippage {
+            Some(max_slippage) => max_slippage,
+            None => config.read().await.max_slippage,
+        };
+
+        let transaction = Self::create_sell_transaction(
+            &order.token_address,
+            order.sell_amount,
+            &max_slippage,
+            wallet,
+            config,
+        )
+        .await;
+
+        let transaction = match transaction {
+            Ok(transaction) => transaction,
+            Err(e) => {
+                // Leave exit_in_flight set so a failed build doesn't get
+                // silently retried forever by the next price tick. No
+                // control RPC call clears the flag; `cancel_trade` marks the
+                // trade `Cancelled`, which also keeps the rebalancer off it.
+                error!("Failed to build sell transaction for {}: {}", order.token_address, e);
+                return Err(e);
+            }
+        };
+
+        let fallback_signature = transaction.signatures.first().map(|s| s.to_string());
+        let config_guard = config.read().await;
+        let submission_result = Self::submit_single(
+            &transaction,
+            &config_guard.confirmation_service,
+            config_guard.tpu_fanout,
+            jito_manager,
+            nozomi_manager,
+            tpu_client,
+            fallback_signature,
+        )
+        .await;
+        let confirmation_service = config_guard.confirmation_service.clone();
+        drop(config_guard);
+
+        let submission_id = match submission_result {
+            Ok(submission_id) => {
+                info!(
+                    "Exit transaction submitted via {} for {}: {}",
+                    confirmation_service, order.token_address, submission_id
+                );
+                submission_id
+            }
+            Err(e) => {
+                error!("Failed to submit exit transaction for {}: {}", order.token_address, e);
+                return Err(e);
+            }
+        };
+
+        let mut trades = active_trades.write().await;
+        if let Some(trade) = trades.get_mut(&order.trade_id) {
+            trade.tokens_held = trade.tokens_held.saturating_sub(order.sell_amount);
+            trade.submission_id = Some(submission_id);
+            trade.exit_in_flight = false;
+            if trade.tokens_held == 0 {
+                trade.status = TradeStatus::Completed;
+            }
+            if let Err(e) = trade_store.put(&order.trade_id, trade) {
+                warn!("Failed to persist trade {} after exit: {}", order.trade_id, e);
+            }
+        }
+        drop(trades);
+
+        latency_metrics.record(&confirmation_service, "exit", submission_start);
+
+        Ok(())
+    }
+
     async fn create_mev_transactions(
         signal: &MEVSignal,
         wallet: &Keypair,
+        config: &Arc<RwLock<Config>>,
+        simulation_guard: &Arc<SimulationGuard>,
     ) -> Result<Vec<Transaction>> {
         // This would create the actual MEV transactions based on the strategy
         // For now, we'll create placeholder transactions
@@ -472,7 +1322,9 @@ impl SniperBot {
                     1000000, // 0.001 SOL
                     &signal.execution_plan.max_slippage,
                     wallet,
-                ).await?);
+                    config,
+                    simulation_guard,
+                ).await?.0);
             }
             crate::config::MEVStrategy::FrontRun => {
                 // Create front-running transactions
@@ -482,7 +1334,9 @@ impl SniperBot {
                     2000000, // 0.002 SOL
                     &signal.execution_plan.max_slippage,
                     wallet,
-                ).await?);
+                    config,
+                    simulation_guard,
+                ).await?.0);
             }
             crate::config::MEVStrategy::Sandwich => {
                 // Create sandwich attack transactions
@@ -492,7 +1346,9 @@ impl SniperBot {
                     1500000, // 0.0015 SOL
                     &signal.execution_plan.max_slippage,
                     wallet,
-                ).await?);
+                    config,
+                    simulation_guard,
+                ).await?.0);
             }
             crate::config::MEVStrategy::BackRun => {
                 // Create back-running transactions
@@ -502,7 +1358,9 @@ impl SniperBot {
                     800000, // 0.0008 SOL
                     &signal.execution_plan.max_slippage,
                     wallet,
-                ).await?);
+                    config,
+                    simulation_guard,
+                ).await?.0);
             }
             crate::config::MEVStrategy::Liquidation => {
                 // Create liquidation transactions
@@ -512,7 +1370,9 @@ impl SniperBot {
                     5000000, // 0.005 SOL
                     &signal.execution_plan.max_slippage,
                     wallet,
-                ).await?);
+                    config,
+                    simulation_guard,
+                ).await?.0);
             }
         }
         
@@ -0,0 +1,80 @@
+use anyhow::{anyhow, Result};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// Byte offsets of the two reserve fields within a PumpSwap pool account,
+/// after the program's discriminator and the base/quote mint pubkeys.
+const RESERVE_IN_OFFSET: usize = 8 + 32 + 32;
+const RESERVE_OUT_OFFSET: usize = RESERVE_IN_OFFSET + 8;
+
+/// A pool's reserves and the slot they were observed at, captured both at
+/// trade-decision time and again immediately before submission so the two
+/// can be diffed by [`crate::utils::validate_state_snapshot`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoolStateSnapshot {
+    pub reserve_in: u64,
+    pub reserve_out: u64,
+    pub slot: u64,
+}
+
+/// Captures and re-observes on-chain pool reserves so a snipe can be
+/// aborted if the pool moved out from under it between the decision to
+/// trade and the moment the transaction is actually submitted to
+/// `jito_client`/`nozomi_client` — exactly the window a competing bot in
+/// the same slot would exploit.
+pub struct StateGuard {
+    rpc_client: RpcClient,
+}
+
+impl StateGuard {
+    pub fn new(rpc_url: String) -> Self {
+        Self {
+            rpc_client: RpcClient::new(rpc_url),
+        }
+    }
+
+    /// Read `pool_address`'s current reserves and the slot they were
+    /// observed at.
+    pub fn capture(&self, pool_address: &str) -> Result<PoolStateSnapshot> {
+        let pubkey = Pubkey::from_str(pool_address)
+            .map_err(|e| anyhow!("Invalid pool address {}: {}", pool_address, e))?;
+
+        let response = self
+            .rpc_client
+            .get_account_with_commitment(&pubkey, CommitmentConfig::processed())
+            .map_err(|e| anyhow!("Failed to fetch pool account {}: {}", pool_address, e))?;
+
+        let account = response
+            .value
+            .ok_or_else(|| anyhow!("Pool account {} not found", pool_address))?;
+        let slot = response.context.slot;
+
+        let data = &account.data;
+        if data.len() < RESERVE_OUT_OFFSET + 8 {
+            return Err(anyhow!(
+                "Pool account {} data too short ({} bytes) to contain reserves",
+                pool_address,
+                data.len()
+            ));
+        }
+
+        let reserve_in = u64::from_le_bytes(
+            data[RESERVE_IN_OFFSET..RESERVE_IN_OFFSET + 8]
+                .try_into()
+                .unwrap(),
+        );
+        let reserve_out = u64::from_le_bytes(
+            data[RESERVE_OUT_OFFSET..RESERVE_OUT_OFFSET + 8]
+                .try_into()
+                .unwrap(),
+        );
+
+        Ok(PoolStateSnapshot {
+            reserve_in,
+            reserve_out,
+            slot,
+        })
+    }
+}
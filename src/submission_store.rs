@@ -0,0 +1,187 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Status of a tracked submission, mirrors the statuses `NozomiStatus`
+/// already reports over the relay plus a `Pending` state for anything still
+/// in flight when it was first recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubmissionStatus {
+    Pending,
+    Confirmed,
+    Failed,
+}
+
+/// A single submission's durable record: what was sent, through which
+/// backend, and how it resolved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmissionRecord {
+    pub submission_id: String,
+    pub backend: String, // "nozomi", "tpu", or "both"
+    pub status: SubmissionStatus,
+    pub confirmation_time_ms: Option<u64>,
+    pub created_at: u64,
+}
+
+impl SubmissionRecord {
+    pub fn new(submission_id: String, backend: String) -> Result<Self> {
+        Ok(Self {
+            submission_id,
+            backend,
+            status: SubmissionStatus::Pending,
+            confirmation_time_ms: None,
+            created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        })
+    }
+}
+
+/// Durable submission tracking, so `NozomiManager` doesn't forget every
+/// in-flight submission across a restart the way the bare in-memory
+/// `pending_submissions` map does. Trait-backed so the default build can
+/// stay database-free: [`NoopSubmissionStore`] is the default, and
+/// [`PostgresSubmissionStore`] is opt-in behind the `postgres-store`
+/// cargo feature.
+#[async_trait]
+pub trait SubmissionStore: Send + Sync {
+    async fn record_submission(&self, record: &SubmissionRecord) -> Result<()>;
+    async fn update_status(&self, submission_id: &str, status: SubmissionStatus, confirmation_time_ms: Option<u64>) -> Result<()>;
+    /// Everything still `Pending` as of the last recorded status, for
+    /// resuming confirmation tracking on startup.
+    async fn load_unresolved(&self) -> Result<Vec<SubmissionRecord>>;
+}
+
+/// Default store: records nothing and reloads nothing. Equivalent to the
+/// pre-existing behavior where `pending_submissions` just lives in memory
+/// and is gone on restart, for users who don't want to stand up Postgres.
+pub struct NoopSubmissionStore;
+
+#[async_trait]
+impl SubmissionStore for NoopSubmissionStore {
+    async fn record_submission(&self, _record: &SubmissionRecord) -> Result<()> {
+        Ok(())
+    }
+
+    async fn update_status(&self, _submission_id: &str, _status: SubmissionStatus, _confirmation_time_ms: Option<u64>) -> Result<()> {
+        Ok(())
+    }
+
+    async fn load_unresolved(&self) -> Result<Vec<SubmissionRecord>> {
+        Ok(Vec::new())
+    }
+}
+
+/// Postgres-backed store via a `bb8` connection pool over `tokio-postgres`.
+/// Gated behind the `postgres-store` feature (add `bb8`, `bb8-postgres`, and
+/// `tokio-postgres` as dependencies and declare the feature in `Cargo.toml`
+/// to build with it) so the default build doesn't need a database driver.
+#[cfg(feature = "postgres-store")]
+pub mod postgres {
+    use super::{SubmissionRecord, SubmissionStatus, SubmissionStore};
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use bb8::Pool;
+    use bb8_postgres::PostgresConnectionManager;
+    use tokio_postgres::NoTls;
+
+    pub struct PostgresSubmissionStore {
+        pool: Pool<PostgresConnectionManager<NoTls>>,
+    }
+
+    impl PostgresSubmissionStore {
+        pub async fn new(database_url: &str) -> Result<Self> {
+            let manager = PostgresConnectionManager::new_from_stringlike(database_url, NoTls)?;
+            let pool = Pool::builder().build(manager).await?;
+
+            let conn = pool.get().await?;
+            conn.execute(
+                "CREATE TABLE IF NOT EXISTS submissions (
+                    submission_id TEXT PRIMARY KEY,
+                    backend TEXT NOT NULL,
+                    status TEXT NOT NULL,
+                    confirmation_time_ms BIGINT,
+                    created_at BIGINT NOT NULL
+                )",
+                &[],
+            )
+            .await?;
+
+            Ok(Self { pool })
+        }
+
+        fn status_to_str(status: SubmissionStatus) -> &'static str {
+            match status {
+                SubmissionStatus::Pending => "pending",
+                SubmissionStatus::Confirmed => "confirmed",
+                SubmissionStatus::Failed => "failed",
+            }
+        }
+
+        fn status_from_str(status: &str) -> SubmissionStatus {
+            match status {
+                "confirmed" => SubmissionStatus::Confirmed,
+                "failed" => SubmissionStatus::Failed,
+                _ => SubmissionStatus::Pending,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SubmissionStore for PostgresSubmissionStore {
+        async fn record_submission(&self, record: &SubmissionRecord) -> Result<()> {
+            let conn = self.pool.get().await?;
+            conn.execute(
+                "INSERT INTO submissions (submission_id, backend, status, confirmation_time_ms, created_at)
+                 VALUES ($1, $2, $3, $4, $5)
+                 ON CONFLICT (submission_id) DO NOTHING",
+                &[
+                    &record.submission_id,
+                    &record.backend,
+                    &Self::status_to_str(record.status),
+                    &record.confirmation_time_ms.map(|v| v as i64),
+                    &(record.created_at as i64),
+                ],
+            )
+            .await?;
+            Ok(())
+        }
+
+        async fn update_status(&self, submission_id: &str, status: SubmissionStatus, confirmation_time_ms: Option<u64>) -> Result<()> {
+            let conn = self.pool.get().await?;
+            conn.execute(
+                "UPDATE submissions SET status = $2, confirmation_time_ms = COALESCE($3, confirmation_time_ms) WHERE submission_id = $1",
+                &[
+                    &submission_id,
+                    &Self::status_to_str(status),
+                    &confirmation_time_ms.map(|v| v as i64),
+                ],
+            )
+            .await?;
+            Ok(())
+        }
+
+        async fn load_unresolved(&self) -> Result<Vec<SubmissionRecord>> {
+            let conn = self.pool.get().await?;
+            let rows = conn
+                .query(
+                    "SELECT submission_id, backend, status, confirmation_time_ms, created_at FROM submissions WHERE status = 'pending'",
+                    &[],
+                )
+                .await?;
+
+            Ok(rows
+                .into_iter()
+                .map(|row| SubmissionRecord {
+                    submission_id: row.get(0),
+                    backend: row.get(1),
+                    status: Self::status_from_str(row.get(2)),
+                    confirmation_time_ms: row.get::<_, Option<i64>>(3).map(|v| v as u64),
+                    created_at: row.get::<_, i64>(4) as u64,
+                })
+                .collect())
+        }
+    }
+}
+
+#[cfg(feature = "postgres-store")]
+pub use postgres::PostgresSubmissionStore;
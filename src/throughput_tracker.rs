@@ -0,0 +1,105 @@
+use dashmap::DashMap;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// How many seconds of per-second buckets `submitted_per_sec`/`confirmed_per_sec`
+/// average over, mirroring lite-rpc's custom sender throughput window.
+const WINDOW_SECS: u64 = 60;
+
+/// Rolling submitted/confirmed transactions-per-second counters plus a
+/// per-route win tally, for [`crate::nozomi_client::NozomiManager::submit_raced`]
+/// to report which route (Nozomi relay, which base URL, or direct TPU)
+/// actually lands trades. Kept separate from [`crate::latency_histogram::ConfirmationMetrics`]
+/// since that one tracks latency/outcome for ordinary single-route
+/// submissions, while this tracks race throughput and route attribution.
+pub struct ThroughputTracker {
+    submitted_buckets: Mutex<VecDeque<(u64, u64)>>,
+    confirmed_buckets: Mutex<VecDeque<(u64, u64)>>,
+    route_wins: DashMap<String, AtomicU64>,
+}
+
+impl ThroughputTracker {
+    pub fn new() -> Self {
+        Self {
+            submitted_buckets: Mutex::new(VecDeque::new()),
+            confirmed_buckets: Mutex::new(VecDeque::new()),
+            route_wins: DashMap::new(),
+        }
+    }
+
+    fn now_secs() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    fn bump(buckets: &Mutex<VecDeque<(u64, u64)>>) {
+        let now = Self::now_secs();
+        let mut buckets = buckets.lock().unwrap();
+
+        match buckets.back_mut() {
+            Some((secs, count)) if *secs == now => *count += 1,
+            _ => buckets.push_back((now, 1)),
+        }
+
+        let cutoff = now.saturating_sub(WINDOW_SECS);
+        while matches!(buckets.front(), Some((secs, _)) if *secs < cutoff) {
+            buckets.pop_front();
+        }
+    }
+
+    fn rate(buckets: &Mutex<VecDeque<(u64, u64)>>) -> f64 {
+        let now = Self::now_secs();
+        let cutoff = now.saturating_sub(WINDOW_SECS);
+        let buckets = buckets.lock().unwrap();
+
+        let total: u64 = buckets
+            .iter()
+            .filter(|(secs, _)| *secs >= cutoff)
+            .map(|(_, count)| count)
+            .sum();
+
+        total as f64 / WINDOW_SECS as f64
+    }
+
+    /// Record that a raced submission went out, regardless of which routes
+    /// it was sent over.
+    pub fn record_submitted(&self) {
+        Self::bump(&self.submitted_buckets);
+    }
+
+    /// Record that a raced submission confirmed via `route`, crediting that
+    /// route's win tally.
+    pub fn record_confirmed(&self, route: &str) {
+        Self::bump(&self.confirmed_buckets);
+        self.route_wins
+            .entry(route.to_string())
+            .or_insert_with(|| AtomicU64::new(0))
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Submissions/sec averaged over the trailing [`WINDOW_SECS`].
+    pub fn submitted_per_sec(&self) -> f64 {
+        Self::rate(&self.submitted_buckets)
+    }
+
+    /// Confirmations/sec averaged over the trailing [`WINDOW_SECS`].
+    pub fn confirmed_per_sec(&self) -> f64 {
+        Self::rate(&self.confirmed_buckets)
+    }
+
+    /// How many raced submissions each route has won, for a caller to print
+    /// e.g. "nozomi:https://... -> 42, tpu -> 13".
+    pub fn route_wins(&self) -> Vec<(String, u64)> {
+        self.route_wins
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().load(Ordering::Relaxed)))
+            .collect()
+    }
+}
+
+impl Default for ThroughputTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
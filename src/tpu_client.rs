@@ -0,0 +1,306 @@
+use anyhow::Result;
+use tracing::{debug, error, info, warn};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, transaction::Transaction};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use crate::config::Config;
+use crate::monitoring::Monitoring;
+use crate::utils::load_identity_keypair;
+
+/// Direct-to-validator submission path over QUIC, bypassing the RPC/bundle relay hop.
+///
+/// Mirrors lite-rpc's custom-tpu-send design: one background task keeps a
+/// `Pubkey -> TPU SocketAddr` map fresh from `get_cluster_nodes`, another keeps
+/// the leader schedule for the next few slots warm, and `send_transaction`
+/// fans the signed transaction out to those leaders concurrently.
+pub struct TpuClient {
+    rpc_client: RpcClient,
+    config: Arc<RwLock<Config>>,
+    tpu_addresses: Arc<RwLock<HashMap<Pubkey, SocketAddr>>>,
+    upcoming_leaders: Arc<RwLock<Vec<Pubkey>>>,
+    /// Identity presented on outbound QUIC connections for staked priority.
+    identity: Arc<Keypair>,
+    /// Client-side QUIC endpoint transactions are sent from; one endpoint is
+    /// reused for every leader rather than binding a fresh UDP socket per send.
+    endpoint: quinn::Endpoint,
+    /// Open connections keyed by leader identity so back-to-back sends to a
+    /// leader that's still in its slot window reuse the same QUIC connection
+    /// instead of paying a fresh handshake every time.
+    connections: Arc<RwLock<HashMap<Pubkey, quinn::Connection>>>,
+}
+
+impl TpuClient {
+    pub fn new(config: Arc<RwLock<Config>>) -> Result<Self> {
+        let config_guard = config.read().unwrap();
+        let rpc_client = RpcClient::new(config_guard.solana_rpc_url.clone());
+        let identity = load_identity_keypair(config_guard.identity_keypair_path.as_deref());
+        drop(config_guard);
+
+        let endpoint = Self::build_quic_endpoint()?;
+
+        Ok(Self {
+            rpc_client,
+            config,
+            tpu_addresses: Arc::new(RwLock::new(HashMap::new())),
+            upcoming_leaders: Arc::new(RwLock::new(Vec::new())),
+            identity: Arc::new(identity),
+            endpoint,
+            connections: Arc::new(RwLock::new(HashMap::new())),
+        })
+    }
+
+    /// Client endpoint used to open connections to every leader's TPU QUIC
+    /// port. Validators present self-signed certificates on this port, so —
+    /// same as `solana-streamer`'s own QUIC client — certificate verification
+    /// is disabled here; authenticity is enforced at the Solana protocol
+    /// layer by the transaction's own signatures, not by TLS.
+    fn build_quic_endpoint() -> Result<quinn::Endpoint> {
+        let crypto = rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_custom_certificate_verifier(Arc::new(SkipServerVerification))
+            .with_no_client_auth();
+
+        let client_config = quinn::ClientConfig::new(Arc::new(crypto));
+        let mut endpoint = quinn::Endpoint::client("0.0.0.0:0".parse()?)?;
+        endpoint.set_default_client_config(client_config);
+
+        Ok(endpoint)
+    }
+
+    /// Spawn the two background refresh loops. Call once after construction.
+    pub fn start_background_tasks(&self) {
+        let cluster_nodes_handle = {
+            let rpc_client = self.rpc_client.url();
+            let tpu_addresses = self.tpu_addresses.clone();
+            tokio::spawn(async move {
+                let rpc_client = RpcClient::new(rpc_client);
+                loop {
+                    match Self::refresh_tpu_addresses(&rpc_client).await {
+                        Ok(addresses) => {
+                            let count = addresses.len();
+                            *tpu_addresses.write().await = addresses;
+                            debug!("Refreshed TPU address map: {} validators", count);
+                        }
+                        Err(e) => warn!("Failed to refresh cluster nodes: {}", e),
+                    }
+
+                    tokio::time::sleep(Duration::from_secs(10)).await;
+                }
+            })
+        };
+
+        let leader_schedule_handle = {
+            let rpc_client = self.rpc_client.url();
+            let upcoming_leaders = self.upcoming_leaders.clone();
+            tokio::spawn(async move {
+                let rpc_client = RpcClient::new(rpc_client);
+                loop {
+                    match Self::refresh_leader_schedule(&rpc_client).await {
+                        Ok(leaders) => {
+                            *upcoming_leaders.write().await = leaders;
+                        }
+                        Err(e) => warn!("Failed to refresh leader schedule: {}", e),
+                    }
+
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                }
+            })
+        };
+
+        // Detach; these loops run for the lifetime of the process.
+        std::mem::drop(cluster_nodes_handle);
+        std::mem::drop(leader_schedule_handle);
+    }
+
+    async fn refresh_tpu_addresses(rpc_client: &RpcClient) -> Result<HashMap<Pubkey, SocketAddr>> {
+        let nodes = rpc_client.get_cluster_nodes()?;
+        let mut addresses = HashMap::new();
+
+        for node in nodes {
+            if let Some(tpu_quic) = node.tpu_quic.or(node.tpu) {
+                if let Ok(pubkey) = node.pubkey.parse::<Pubkey>() {
+                    addresses.insert(pubkey, tpu_quic);
+                }
+            }
+        }
+
+        Ok(addresses)
+    }
+
+    async fn refresh_leader_schedule(rpc_client: &RpcClient) -> Result<Vec<Pubkey>> {
+        let current_slot = rpc_client.get_slot()?;
+        let schedule = rpc_client
+            .get_leader_schedule(Some(current_slot))?
+            .ok_or_else(|| anyhow::anyhow!("Leader schedule unavailable for slot {}", current_slot))?;
+
+        let slot_index = (current_slot % 432000) as usize; // slots per epoch
+        let mut leaders = Vec::new();
+
+        for (identity, slots) in schedule {
+            if slots.iter().any(|s| *s >= slot_index && *s < slot_index + 16) {
+                if let Ok(pubkey) = identity.parse::<Pubkey>() {
+                    leaders.push(pubkey);
+                }
+            }
+        }
+
+        Ok(leaders)
+    }
+
+    /// Fan the signed transaction out to the next `fanout` upcoming leaders over QUIC.
+    pub async fn send_transaction(&self, transaction: &Transaction, fanout: usize) -> Result<()> {
+        let leaders = self.upcoming_leaders.read().await;
+        let tpu_addresses = self.tpu_addresses.read().await;
+
+        let targets: Vec<(Pubkey, SocketAddr)> = leaders
+            .iter()
+            .filter_map(|leader| tpu_addresses.get(leader).map(|addr| (*leader, *addr)))
+            .take(fanout)
+            .collect();
+
+        drop(leaders);
+        drop(tpu_addresses);
+
+        if targets.is_empty() {
+            return Err(anyhow::anyhow!("No resolved TPU addresses for upcoming leaders"));
+        }
+
+        let wire_transaction = bincode::serialize(transaction)?;
+        let connect_timeout_ms = self.config.read().await.tpu_quic_connect_timeout_ms;
+
+        let mut send_futures = Vec::new();
+        for (leader, target) in &targets {
+            send_futures.push(self.send_to_leader(*leader, *target, wire_transaction.clone(), connect_timeout_ms));
+        }
+
+        let results = futures::future::join_all(send_futures).await;
+        let successes = results.iter().filter(|r| r.is_ok()).count();
+
+        info!(
+            "Sent transaction to {}/{} leaders over QUIC",
+            successes,
+            targets.len()
+        );
+
+        if successes == 0 {
+            return Err(anyhow::anyhow!("Failed to deliver transaction to any leader"));
+        }
+
+        Ok(())
+    }
+
+    /// Sends one packet to `leader`'s TPU QUIC port, reusing a pooled
+    /// connection when one is still open. Every failure mode gets its own
+    /// counter (connect, write, timeout, stale-leader) since QUIC errors are
+    /// otherwise silent and indistinguishable from each other at the caller.
+    async fn send_to_leader(
+        &self,
+        leader: Pubkey,
+        target: SocketAddr,
+        wire_transaction: Vec<u8>,
+        connect_timeout_ms: u64,
+    ) -> Result<()> {
+        if !self.upcoming_leaders.read().await.contains(&leader) {
+            // The leader schedule refreshes in the background every few
+            // seconds; if `leader` rotated out of the upcoming window
+            // between resolving `targets` and getting here, sending would
+            // just hand the packet to a validator that's no longer about to
+            // produce a block for it.
+            Monitoring::record_tpu_quic_stale_leader_drop();
+            return Err(anyhow::anyhow!("Leader {} rotated out of the upcoming window before send", leader));
+        }
+
+        let timeout = Duration::from_millis(connect_timeout_ms);
+
+        let connection = match tokio::time::timeout(timeout, self.get_or_open_connection(leader, target)).await {
+            Ok(Ok(connection)) => connection,
+            Ok(Err(e)) => {
+                Monitoring::record_tpu_quic_connect_error();
+                return Err(e);
+            }
+            Err(_) => {
+                Monitoring::record_tpu_quic_timeout();
+                return Err(anyhow::anyhow!("QUIC connect to leader {} timed out", leader));
+            }
+        };
+
+        let write_result = tokio::time::timeout(timeout, async {
+            let mut stream = connection.open_uni().await?;
+            stream.write_all(&wire_transaction).await?;
+            stream.finish().await?;
+            Ok::<(), anyhow::Error>(())
+        })
+        .await;
+
+        match write_result {
+            Ok(Ok(())) => {
+                debug!("Dispatched {} bytes to leader TPU {} ({})", wire_transaction.len(), target, leader);
+                Ok(())
+            }
+            Ok(Err(e)) => {
+                Monitoring::record_tpu_quic_write_error();
+                self.connections.write().await.remove(&leader);
+                Err(e)
+            }
+            Err(_) => {
+                Monitoring::record_tpu_quic_timeout();
+                self.connections.write().await.remove(&leader);
+                Err(anyhow::anyhow!("QUIC write to leader {} timed out", leader))
+            }
+        }
+    }
+
+    /// Reuses a pooled connection to `leader` if it's still open, otherwise
+    /// dials a fresh one and pools it keyed by leader identity.
+    async fn get_or_open_connection(&self, leader: Pubkey, target: SocketAddr) -> Result<quinn::Connection> {
+        if let Some(connection) = self.connections.read().await.get(&leader) {
+            if connection.close_reason().is_none() {
+                return Ok(connection.clone());
+            }
+        }
+
+        let connection = self.endpoint.connect(target, "solana-tpu")?.await?;
+        self.connections.write().await.insert(leader, connection.clone());
+        Ok(connection)
+    }
+}
+
+/// Accepts any certificate a leader's TPU QUIC port presents. Validators use
+/// ephemeral self-signed certificates identified by their node pubkey, not
+/// certificates chaining to a CA, so this mirrors the verifier
+/// `solana-streamer`'s own QUIC client installs rather than implementing TLS
+/// trust the protocol doesn't actually provide.
+struct SkipServerVerification;
+
+impl rustls::client::ServerCertVerifier for SkipServerVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &rustls::Certificate,
+        _intermediates: &[rustls::Certificate],
+        _server_name: &rustls::ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: std::time::SystemTime,
+    ) -> Result<rustls::client::ServerCertVerified, rustls::Error> {
+        Ok(rustls::client::ServerCertVerified::assertion())
+    }
+}
+
+impl Clone for TpuClient {
+    fn clone(&self) -> Self {
+        Self {
+            rpc_client: RpcClient::new(self.rpc_client.url()),
+            config: self.config.clone(),
+            tpu_addresses: self.tpu_addresses.clone(),
+            upcoming_leaders: self.upcoming_leaders.clone(),
+            identity: self.identity.clone(),
+            endpoint: self.endpoint.clone(),
+            connections: self.connections.clone(),
+        }
+    }
+}
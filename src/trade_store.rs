@@ -0,0 +1,154 @@
+use anyhow::Result;
+use tracing::warn;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::sniper::{ActiveTrade, TradeStatus};
+
+/// Embedded key-value persistence for in-flight trades, so a restart doesn't
+/// forget a token the bot still needs to sell. Backed by `sled` rather than
+/// a SQL store since trades are simple id -> record lookups with no joins.
+pub struct TradeStore {
+    db: sled::Db,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PersistedTrade {
+    token_address: String,
+    amount: u64,
+    target_price: f64,
+    max_slippage: f64,
+    created_at: u64,
+    status: PersistedTradeStatus,
+    submission_id: Option<String>,
+    signature: Option<String>,
+    entry_price_usd: f64,
+    peak_price_usd: f64,
+    tokens_held: u64,
+    initial_tokens: u64,
+    ladder_progress: usize,
+    exit_in_flight: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum PersistedTradeStatus {
+    Pending,
+    Executing,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl From<TradeStatus> for PersistedTradeStatus {
+    fn from(status: TradeStatus) -> Self {
+        match status {
+            TradeStatus::Pending => PersistedTradeStatus::Pending,
+            TradeStatus::Executing => PersistedTradeStatus::Executing,
+            TradeStatus::Completed => PersistedTradeStatus::Completed,
+            TradeStatus::Failed => PersistedTradeStatus::Failed,
+            TradeStatus::Cancelled => PersistedTradeStatus::Cancelled,
+        }
+    }
+}
+
+impl From<PersistedTradeStatus> for TradeStatus {
+    fn from(status: PersistedTradeStatus) -> Self {
+        match status {
+            PersistedTradeStatus::Pending => TradeStatus::Pending,
+            PersistedTradeStatus::Executing => TradeStatus::Executing,
+            PersistedTradeStatus::Completed => TradeStatus::Completed,
+            PersistedTradeStatus::Failed => TradeStatus::Failed,
+            PersistedTradeStatus::Cancelled => TradeStatus::Cancelled,
+        }
+    }
+}
+
+impl From<&ActiveTrade> for PersistedTrade {
+    fn from(trade: &ActiveTrade) -> Self {
+        Self {
+            token_address: trade.token_address.clone(),
+            amount: trade.amount,
+            target_price: trade.target_price,
+            max_slippage: trade.max_slippage,
+            created_at: trade.created_at,
+            status: trade.status.clone().into(),
+            submission_id: trade.submission_id.clone(),
+            signature: trade.signature.clone(),
+            entry_price_usd: trade.entry_price_usd,
+            peak_price_usd: trade.peak_price_usd,
+            tokens_held: trade.tokens_held,
+            initial_tokens: trade.initial_tokens,
+            ladder_progress: trade.ladder_progress,
+            exit_in_flight: trade.exit_in_flight,
+        }
+    }
+}
+
+impl From<PersistedTrade> for ActiveTrade {
+    fn from(trade: PersistedTrade) -> Self {
+        Self {
+            token_address: trade.token_address,
+            amount: trade.amount,
+            target_price: trade.target_price,
+            max_slippage: trade.max_slippage,
+            created_at: trade.created_at,
+            status: trade.status.into(),
+            submission_id: trade.submission_id,
+            signature: trade.signature,
+            entry_price_usd: trade.entry_price_usd,
+            peak_price_usd: trade.peak_price_usd,
+            tokens_held: trade.tokens_held,
+            initial_tokens: trade.initial_tokens,
+            ladder_progress: trade.ladder_progress,
+            exit_in_flight: trade.exit_in_flight,
+        }
+    }
+}
+
+impl TradeStore {
+    pub fn open(path: &str) -> Result<Self> {
+        let db = sled::open(path)?;
+        Ok(Self { db })
+    }
+
+    /// Write (or overwrite) a trade's current state. Called on every status
+    /// transition so a crash mid-execution loses at most the in-flight step.
+    pub fn put(&self, trade_id: &str, trade: &ActiveTrade) -> Result<()> {
+        let persisted = PersistedTrade::from(trade);
+        let bytes = bincode::serialize(&persisted)?;
+        self.db.insert(trade_id.as_bytes(), bytes)?;
+        self.db.flush()?;
+        Ok(())
+    }
+
+    pub fn remove(&self, trade_id: &str) -> Result<()> {
+        self.db.remove(trade_id.as_bytes())?;
+        self.db.flush()?;
+        Ok(())
+    }
+
+    /// Load every persisted trade, keyed by trade id.
+    pub fn load_all(&self) -> Result<HashMap<String, ActiveTrade>> {
+        let mut trades = HashMap::new();
+
+        for entry in self.db.iter() {
+            let (key, value) = entry?;
+            let trade_id = match std::str::from_utf8(&key) {
+                Ok(id) => id.to_string(),
+                Err(e) => {
+                    warn!("Skipping trade record with non-utf8 key: {}", e);
+                    continue;
+                }
+            };
+
+            match bincode::deserialize::<PersistedTrade>(&value) {
+                Ok(persisted) => {
+                    trades.insert(trade_id, persisted.into());
+                }
+                Err(e) => warn!("Failed to deserialize persisted trade {}: {}", trade_id, e),
+            }
+        }
+
+        Ok(trades)
+    }
+}
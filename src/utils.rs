@@ -1,5 +1,6 @@
 use anyhow::Result;
-use log::{debug, info, warn};
+use tracing::{debug, info, warn};
+use serde_json;
 use solana_sdk::{
     pubkey::Pubkey,
     signature::Keypair,
@@ -7,6 +8,14 @@ use solana_sdk::{
 };
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use crate::money::{FixedU256, Lamports};
+use crate::pool_model::PoolModel;
+use crate::state_guard::PoolStateSnapshot;
+
+/// Solana's approximate slot duration, used to convert a slot drift into a
+/// wall-clock staleness bound against `transaction_timeout`.
+const APPROX_SLOT_DURATION: Duration = Duration::from_millis(400);
+
 pub fn generate_trade_id() -> String {
     use rand::Rng;
     let mut rng = rand::thread_rng();
@@ -22,25 +31,12 @@ pub fn generate_bundle_id() -> String {
 }
 
 pub fn calculate_price_impact(
+    pool_model: &PoolModel,
     input_amount: u64,
     output_amount: u64,
     pool_reserves: (u64, u64),
 ) -> f64 {
-    let (reserve_in, reserve_out) = pool_reserves;
-    
-    if reserve_in == 0 || reserve_out == 0 {
-        return 0.0;
-    }
-    
-    // Calculate price impact using constant product formula
-    let new_reserve_in = reserve_in + input_amount;
-    let new_reserve_out = reserve_out - output_amount;
-    
-    let price_before = reserve_out as f64 / reserve_in as f64;
-    let price_after = new_reserve_out as f64 / new_reserve_in as f64;
-    
-    let price_impact = (price_before - price_after) / price_before * 100.0;
-    price_impact.max(0.0)
+    pool_model.price_impact(input_amount, output_amount, pool_reserves)
 }
 
 pub fn calculate_slippage(
@@ -89,56 +85,27 @@ pub fn validate_pool_address(address: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn format_amount(amount: u64, decimals: u8) -> String {
-    let divisor = 10_u64.pow(decimals as u32);
-    let whole = amount / divisor;
-    let fraction = amount % divisor;
-    
-    if fraction == 0 {
-        format!("{}", whole)
-    } else {
-        format!("{}.{:0width$}", whole, fraction, width = decimals as usize)
-    }
+pub fn format_amount(amount: FixedU256) -> String {
+    amount.to_string()
 }
 
-pub fn parse_amount(amount_str: &str, decimals: u8) -> Result<u64> {
-    let parts: Vec<&str> = amount_str.split('.').collect();
-    
-    match parts.len() {
-        1 => {
-            let whole: u64 = parts[0].parse()?;
-            Ok(whole * 10_u64.pow(decimals as u32))
-        }
-        2 => {
-            let whole: u64 = parts[0].parse()?;
-            let fraction_str = parts[1];
-            
-            if fraction_str.len() > decimals as usize {
-                return Err(anyhow::anyhow!("Too many decimal places"));
-            }
-            
-            let fraction: u64 = format!("{:0<width$}", fraction_str, width = decimals as usize).parse()?;
-            
-            Ok(whole * 10_u64.pow(decimals as u32) + fraction)
-        }
-        _ => Err(anyhow::anyhow!("Invalid amount format")),
-    }
+pub fn parse_amount(amount_str: &str, decimals: u8) -> Result<FixedU256> {
+    FixedU256::from_decimal_str(amount_str, decimals)
 }
 
 pub fn calculate_profit_margin(
-    buy_price: f64,
-    sell_price: f64,
-    fees: f64,
+    buy_price: Lamports,
+    sell_price: Lamports,
+    fees: Lamports,
 ) -> f64 {
-    if buy_price == 0.0 {
+    if buy_price == Lamports::ZERO {
         return 0.0;
     }
-    
-    let gross_profit = sell_price - buy_price;
-    let net_profit = gross_profit - fees;
-    let profit_margin = (net_profit / buy_price) * 100.0;
-    
-    profit_margin
+
+    let gross_profit = sell_price.0 as i64 - buy_price.0 as i64;
+    let net_profit = gross_profit - fees.0 as i64;
+
+    (net_profit as f64 / buy_price.0 as f64) * 100.0
 }
 
 pub fn estimate_transaction_fee(
@@ -160,46 +127,100 @@ pub fn estimate_transaction_fee(
 }
 
 pub fn calculate_optimal_slippage(
-    liquidity: u64,
+    pool_model: &PoolModel,
+    pool_reserves: (u64, u64),
     trade_size: u64,
     volatility: f64,
 ) -> f64 {
-    // Base slippage calculation
-    let liquidity_ratio = trade_size as f64 / liquidity as f64;
-    let base_slippage = liquidity_ratio * 100.0;
-    
+    // Base slippage is the pool model's own predicted price impact for this
+    // trade size, rather than a flat trade-size/liquidity ratio, so a
+    // StableSwap pool isn't budgeted the same slippage as a constant-product
+    // one of equal depth.
+    let predicted_output = pool_model.predicted_output(trade_size, pool_reserves);
+    let base_slippage = pool_model.price_impact(trade_size, predicted_output, pool_reserves);
+
     // Adjust for volatility
     let volatility_adjustment = volatility * 0.5;
-    
+
     // Add safety margin
     let safety_margin = 0.5;
-    
+
     let optimal_slippage = base_slippage + volatility_adjustment + safety_margin;
-    
+
     // Cap at reasonable maximum
     optimal_slippage.min(10.0) // 10% maximum
 }
 
+/// Aborts a trade whose pool state has moved since `expected` was captured
+/// at decision time: either its reserves have drifted past `max_drift_bps`
+/// basis points, or `observed`'s slot is older/newer than `expected`'s by
+/// more than `transaction_timeout` of wall-clock time. Called immediately
+/// before handing a transaction to `jito_client`/`nozomi_client`, so a
+/// competing bot that already moved the pool this slot doesn't get sniped
+/// into.
+pub fn validate_state_snapshot(
+    expected: &PoolStateSnapshot,
+    observed: &PoolStateSnapshot,
+    max_drift_bps: u64,
+    transaction_timeout: Duration,
+) -> Result<()> {
+    let slots_elapsed = observed.slot.abs_diff(expected.slot);
+    let elapsed = APPROX_SLOT_DURATION * slots_elapsed as u32;
+    if elapsed > transaction_timeout {
+        return Err(anyhow::anyhow!(
+            "Pool state is stale: {} slots (~{:?}) old, exceeds transaction_timeout of {:?}",
+            slots_elapsed, elapsed, transaction_timeout
+        ));
+    }
+
+    let reserve_in_drift = drift_bps(expected.reserve_in, observed.reserve_in);
+    let reserve_out_drift = drift_bps(expected.reserve_out, observed.reserve_out);
+    let drift = reserve_in_drift.max(reserve_out_drift);
+
+    if drift > max_drift_bps {
+        return Err(anyhow::anyhow!(
+            "Pool reserves drifted {} bps (in: {}, out: {}), exceeds max_reserve_drift_bps of {}",
+            drift, reserve_in_drift, reserve_out_drift, max_drift_bps
+        ));
+    }
+
+    Ok(())
+}
+
+fn drift_bps(expected: u64, observed: u64) -> u64 {
+    if expected == 0 {
+        return 0;
+    }
+
+    let diff = (expected as i128 - observed as i128).unsigned_abs();
+    ((diff * 10_000) / expected as u128) as u64
+}
+
 pub fn is_profitable_trade(
-    expected_profit: f64,
-    gas_cost: u64,
+    expected_profit: Lamports,
+    gas_cost: Lamports,
     risk_factor: f64,
 ) -> bool {
-    let gas_cost_sol = gas_cost as f64 / 1e9; // Convert lamports to SOL
-    let risk_adjusted_profit = expected_profit * (1.0 - risk_factor);
-    
-    risk_adjusted_profit > gas_cost_sol * 2.0 // At least 2x gas cost
+    let risk_adjusted_profit = expected_profit.as_sol() * (1.0 - risk_factor);
+
+    risk_adjusted_profit > gas_cost.as_sol() * 2.0 // At least 2x gas cost
 }
 
 pub fn calculate_position_size(
-    account_balance: u64,
+    account_balance: Lamports,
     risk_percentage: f64,
-    token_price: f64,
+    token_price: Lamports,
 ) -> u64 {
-    let max_risk_amount = (account_balance as f64 * risk_percentage / 100.0) as u64;
-    let position_size = (max_risk_amount as f64 / token_price) as u64;
-    
-    position_size
+    // risk_percentage is a whole-number percent (e.g. 5.0 == 5%), so scale the
+    // basis-point numerator by 100 to keep `mul_div`'s arithmetic exact.
+    let risk_bps = (risk_percentage * 100.0).round() as u64;
+    let max_risk_amount = account_balance.mul_div(risk_bps, 10_000).unwrap_or(Lamports::ZERO);
+
+    if token_price == Lamports::ZERO {
+        return 0;
+    }
+
+    max_risk_amount.0 / token_price.0
 }
 
 pub fn format_duration(duration: Duration) -> String {
@@ -217,6 +238,29 @@ pub fn format_duration(duration: Duration) -> String {
     }
 }
 
+/// Load a validator identity keypair from disk for staked QUIC/TPU packet
+/// treatment, falling back to a fresh ephemeral (unstaked) identity when no
+/// path is configured, mirroring lite-rpc's `load_identity_keypair`.
+pub fn load_identity_keypair(path: Option<&str>) -> Keypair {
+    match path {
+        Some(path) => match std::fs::read_to_string(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| serde_json::from_str::<Vec<u8>>(&content).map_err(anyhow::Error::from))
+            .and_then(|bytes| Keypair::from_bytes(&bytes).map_err(anyhow::Error::from))
+        {
+            Ok(keypair) => keypair,
+            Err(e) => {
+                warn!("Failed to load identity keypair from {}: {}, using ephemeral identity", path, e);
+                Keypair::new()
+            }
+        },
+        None => {
+            debug!("No identity keypair configured, using ephemeral unstaked identity");
+            Keypair::new()
+        }
+    }
+}
+
 pub fn get_timestamp() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -239,25 +283,33 @@ pub fn is_within_time_window(
     current_time - timestamp <= window_seconds
 }
 
+/// `oracle_agreement` is `OracleAggregator::consensus_price`'s
+/// `agreement_score` (1.0 == sources agree, 0.0 == past
+/// `oracle_divergence_bps`), letting multi-source confirmation pull the
+/// overall score down the same way a thin or young listing already does.
 pub fn calculate_confidence_score(
     liquidity: u64,
     volume: u64,
     price_stability: f64,
     time_since_listing: u64,
+    oracle_agreement: f64,
 ) -> f64 {
-    // Liquidity score (0-0.4)
-    let liquidity_score = (liquidity as f64 / 1e9).min(100.0) / 100.0 * 0.4;
-    
-    // Volume score (0-0.3)
-    let volume_score = (volume as f64 / 1e6).min(1000.0) / 1000.0 * 0.3;
-    
-    // Price stability score (0-0.2)
-    let stability_score = (1.0 - price_stability).max(0.0) * 0.2;
-    
+    // Liquidity score (0-0.35)
+    let liquidity_score = (liquidity as f64 / 1e9).min(100.0) / 100.0 * 0.35;
+
+    // Volume score (0-0.25)
+    let volume_score = (volume as f64 / 1e6).min(1000.0) / 1000.0 * 0.25;
+
+    // Price stability score (0-0.15)
+    let stability_score = (1.0 - price_stability).max(0.0) * 0.15;
+
     // Time score (0-0.1)
     let time_score = (time_since_listing as f64 / 3600.0).min(24.0) / 24.0 * 0.1;
-    
-    liquidity_score + volume_score + stability_score + time_score
+
+    // Oracle agreement score (0-0.15)
+    let oracle_score = oracle_agreement.clamp(0.0, 1.0) * 0.15;
+
+    liquidity_score + volume_score + stability_score + time_score + oracle_score
 }
 
 pub fn validate_transaction(transaction: &Transaction) -> Result<()> {
@@ -327,6 +379,11 @@ pub fn should_execute_trade(
         && confidence_score >= min_confidence_threshold
 }
 
+/// Emits one structured `tracing` event inside a `trade_metrics` span,
+/// carrying every field as its own key rather than interpolating them into
+/// a single message string, so a JSON log layer (see `main.rs`) or a
+/// `tracing`-aware collector can filter/aggregate on them directly instead
+/// of parsing the message.
 pub fn log_trade_metrics(
     token_address: &str,
     strategy: &str,
@@ -337,6 +394,17 @@ pub fn log_trade_metrics(
     slippage: f64,
     price_impact: f64,
 ) {
-    info!("Trade metrics: token={}, strategy={}, expected_profit={} SOL, actual_profit={} SOL, gas={} lamports, time={}ms, slippage={}%, impact={}%",
-          token_address, strategy, expected_profit, actual_profit, gas_used, execution_time_ms, slippage, price_impact);
+    let span = tracing::info_span!(
+        "trade_metrics",
+        token = token_address,
+        strategy = strategy,
+        expected_profit_sol = expected_profit,
+        actual_profit_sol = actual_profit,
+        gas_used_lamports = gas_used,
+        execution_time_ms = execution_time_ms,
+        slippage_pct = slippage,
+        price_impact_pct = price_impact,
+    );
+    let _enter = span.enter();
+    info!("trade metrics recorded");
 }
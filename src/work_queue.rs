@@ -0,0 +1,104 @@
+use tracing::warn;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+
+use crate::mev_detector::{MEVPriority, MEVSignal};
+use crate::monitoring::Monitoring;
+use crate::proto::pumpswap::TokenListing;
+
+/// Why the rebalancer decided a position should be (partially) closed.
+/// Carried through purely for logging/metrics at the point of submission.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExitReason {
+    TakeProfit,
+    TrailingStop,
+    Timeout,
+}
+
+/// A rebalancer-issued instruction to sell part or all of a held position.
+#[derive(Debug, Clone)]
+pub struct ExitOrder {
+    pub trade_id: String,
+    pub token_address: String,
+    pub sell_amount: u64,
+    pub reason: ExitReason,
+}
+
+/// A unit of execution work handed from a detection callback to the trade
+/// worker pool. Detection (`should_snipe_token` / `MEVDetector::analyze_opportunities`)
+/// stays on the gRPC stream callback's task; only the quote/build/submit
+/// work wrapped here runs on the worker pool, so a slow Jupiter quote can
+/// never stall the stream from draining.
+pub enum TradeJob {
+    Snipe(TokenListing),
+    Mev(MEVSignal),
+    Exit(ExitOrder),
+}
+
+impl TradeJob {
+    /// Fresh-listing snipes are always treated as the highest priority,
+    /// since missing the first block after a listing is the whole point of
+    /// the bot; MEV jobs carry whatever priority `MEVDetector` assigned.
+    /// Exits are just as time-sensitive as snipes, since a queued take-profit
+    /// or stop-loss is a realized gain/loss sitting on the table.
+    pub fn priority(&self) -> MEVPriority {
+        match self {
+            TradeJob::Snipe(_) => MEVPriority::Critical,
+            TradeJob::Mev(signal) => signal.priority.clone(),
+            TradeJob::Exit(_) => MEVPriority::Critical,
+        }
+    }
+}
+
+/// Bounded producer/consumer queue sitting between stream detection and
+/// trade execution. `push` applies backpressure by priority: once the
+/// queue is full, jobs below `High` priority are dropped so a burst of
+/// low-priority MEV noise can't starve the stream callbacks, while
+/// `High`/`Critical` jobs block the producer until a worker frees a slot.
+pub struct TradeQueue {
+    tx: mpsc::Sender<TradeJob>,
+    rx: Arc<Mutex<mpsc::Receiver<TradeJob>>>,
+}
+
+impl TradeQueue {
+    pub fn new(capacity: usize) -> Self {
+        let (tx, rx) = mpsc::channel(capacity);
+        Self {
+            tx,
+            rx: Arc::new(Mutex::new(rx)),
+        }
+    }
+
+    pub async fn push(&self, job: TradeJob) {
+        match self.tx.try_send(job) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(job)) => {
+                if job.priority() >= MEVPriority::High {
+                    if let Err(e) = self.tx.send(job).await {
+                        warn!("Trade queue closed while waiting to enqueue high-priority job: {}", e);
+                    }
+                } else {
+                    warn!("Trade queue full, dropping {:?} priority job", job.priority());
+                }
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                warn!("Trade queue closed, dropping job");
+            }
+        }
+
+        Monitoring::update_queue_size(self.len());
+    }
+
+    /// Clone of the shared receiver handle; every worker task pulls from
+    /// the same underlying channel via this mutex.
+    pub fn receiver(&self) -> Arc<Mutex<mpsc::Receiver<TradeJob>>> {
+        self.rx.clone()
+    }
+
+    /// Number of jobs currently sitting in the queue, derived from the
+    /// channel's own capacity bookkeeping rather than a separate counter
+    /// that could drift out of sync with it.
+    pub fn len(&self) -> usize {
+        self.tx.max_capacity() - self.tx.capacity()
+    }
+}